@@ -1,14 +1,56 @@
 extern crate test;
 use super::*;
 
+const LOREM: &str = include_str!("./lorem.txt");
+
+fn render(bench: &mut test::Bencher, page: usize, font: Font, options: ImageOptions) {
+    bench.iter(|| write_text(LOREM, page, font, options))
+}
+
 #[bench]
 fn lorem_ipsum_5_paragraphs(bench: &mut test::Bencher) {
-    bench.iter(|| {
-        write_text(
-            include_str!("./lorem.txt"),
-            0,
-            fonts::times::TIMES36,
-            ImageOptions::default(),
-        )
-    })
+    render(bench, 0, fonts::times::TIMES36, ImageOptions::default())
+}
+
+/// Benchmarks rendering a page of the text, each with a font, a page number and
+/// options.
+macro_rules! benches {
+    ($($name:ident: $font:expr, $page:expr, $options:expr;)*) => {
+        $(
+            #[bench]
+            fn $name(bench: &mut test::Bencher) {
+                render(bench, $page, $font, $options)
+            }
+        )*
+    };
+}
+
+benches! {
+    // Small and large glyphs, with and without resampling.
+    font_arial18: fonts::arial::ARIAL18, 0, ImageOptions::default();
+    font_atkinson36: fonts::atkinson::ATKINSON36, 0, ImageOptions::default();
+    font_scaled_down: Font::from_family("times", 16.0), 0, ImageOptions::default();
+    font_scaled_up: Font::from_family("times", 48.0), 0, ImageOptions::default();
+
+    page_narrow: fonts::times::TIMES24, 0, ImageOptions {
+        width: 400.0,
+        ..Default::default()
+    };
+    page_wide: fonts::times::TIMES24, 0, ImageOptions {
+        width: 1600.0,
+        ..Default::default()
+    };
+    page_fitted_width: fonts::times::TIMES24, 0, ImageOptions {
+        constant_width: false,
+        ..Default::default()
+    };
+    page_by_lines: fonts::times::TIMES24, 2, ImageOptions {
+        lines: 20,
+        ..Default::default()
+    };
+    page_by_height: fonts::times::TIMES24, 2, ImageOptions {
+        page_height: Some(1200.0),
+        paragraph_spacing: 12.0,
+        ..Default::default()
+    };
 }
@@ -0,0 +1,258 @@
+use alloc::{string::String, vec::Vec};
+
+/// Bidirectional character types, named as in UAX #9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+enum Class {
+    /// Left to right.
+    L,
+    /// Right to left.
+    R,
+    /// Arabic letter.
+    AL,
+    /// European number.
+    EN,
+    /// European number separator.
+    ES,
+    /// European number terminator.
+    ET,
+    /// Arabic number.
+    AN,
+    /// Common number separator.
+    CS,
+    /// Nonspacing mark.
+    NSM,
+    /// Whitespace.
+    WS,
+    /// Other neutral.
+    ON,
+}
+
+fn class(c: char) -> Class {
+    match c {
+        '0'..='9' | '²' | '³' | '¹' | '\u{06F0}'..='\u{06F9}' => Class::EN,
+        '+' | '-' => Class::ES,
+        '#' | '$' | '%' | '°' | '±' | '¢' | '£' | '¤' | '¥' | '€' | '\u{066A}' => Class::ET,
+        ',' | '.' | '/' | ':' | '\u{00A0}' | '\u{060C}' => Class::CS,
+        '\u{0660}'..='\u{0669}' | '\u{066B}' | '\u{066C}' => Class::AN,
+        ' ' | '\t' | '\u{2000}'..='\u{200A}' | '\u{3000}' => Class::WS,
+        '\u{0300}'..='\u{036F}'
+        | '\u{0591}'..='\u{05BD}'
+        | '\u{05BF}'
+        | '\u{05C1}'
+        | '\u{05C2}'
+        | '\u{05C4}'
+        | '\u{05C5}'
+        | '\u{05C7}'
+        | '\u{0610}'..='\u{061A}'
+        | '\u{064B}'..='\u{065F}'
+        | '\u{0670}'
+        | '\u{06D6}'..='\u{06DC}'
+        | '\u{06DF}'..='\u{06E4}'
+        | '\u{06E7}'
+        | '\u{06E8}'
+        | '\u{06EA}'..='\u{06ED}' => Class::NSM,
+        '\u{0590}'..='\u{05FF}' | '\u{07C0}'..='\u{085F}' | '\u{FB1D}'..='\u{FB4F}' => Class::R,
+        '\u{0600}'..='\u{07BF}'
+        | '\u{0860}'..='\u{08FF}'
+        | '\u{FB50}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}' => Class::AL,
+        _ if c.is_alphanumeric() => Class::L,
+        _ => Class::ON,
+    }
+}
+
+/// Whether a paragraph runs right to left, going by its first strongly directional
+/// character.
+pub(crate) fn is_rtl(paragraph: &str) -> bool {
+    paragraph
+        .chars()
+        .map(class)
+        .find(|c| matches!(c, Class::L | Class::R | Class::AL))
+        .is_some_and(|c| c != Class::L)
+}
+
+/// Rearrange a line of text in logical order into the order it is drawn in, left to
+/// right, mirroring brackets in right to left runs.
+///
+/// This follows the implicit rules of the Unicode Bidirectional Algorithm (UAX #9).
+/// Explicit embeddings, overrides and isolates are not supported.
+#[cfg(test)]
+pub(crate) fn reorder(line: &str, rtl: bool) -> String {
+    reorder_mapped(line, rtl).0
+}
+
+/// Rearrange a line of text in logical order into the order it is drawn in, along with
+/// the index in logical order of each character drawn. See [`reorder`].
+pub(crate) fn reorder_mapped(line: &str, rtl: bool) -> (String, Vec<usize>) {
+    let chars: Vec<char> = line.chars().collect();
+    let levels = levels(&chars, rtl);
+
+    let mut order: Vec<usize> = (0..chars.len()).collect();
+    let highest = levels.iter().copied().max().unwrap_or(0);
+    let lowest_odd = levels.iter().copied().filter(|l| l % 2 == 1).min();
+    if let Some(lowest_odd) = lowest_odd {
+        for level in (lowest_odd..=highest).rev() {
+            let mut i = 0;
+            while i < order.len() {
+                if levels[order[i]] < level {
+                    i += 1;
+                    continue;
+                }
+                let start = i;
+                while i < order.len() && levels[order[i]] >= level {
+                    i += 1;
+                }
+                order[start..i].reverse();
+            }
+        }
+    }
+
+    let text = order
+        .iter()
+        .map(|&i| match levels[i] % 2 {
+            1 => mirror(chars[i]),
+            _ => chars[i],
+        })
+        .collect();
+    (text, order)
+}
+
+/// Resolve the embedding level of each character of a line.
+fn levels(chars: &[char], rtl: bool) -> Vec<u8> {
+    let base = rtl as u8;
+    let sos = if rtl { Class::R } else { Class::L };
+    let mut classes: Vec<Class> = chars.iter().map(|&c| class(c)).collect();
+
+    // W1: nonspacing marks take the type of the previous character.
+    let mut previous = sos;
+    for class in &mut classes {
+        if *class == Class::NSM {
+            *class = previous;
+        }
+        previous = *class;
+    }
+
+    // W2, W3: numbers after Arabic letters are Arabic numbers, which are right to left.
+    let mut strong = sos;
+    for class in &mut classes {
+        match *class {
+            Class::L | Class::R => strong = *class,
+            Class::AL => {
+                strong = Class::AL;
+                *class = Class::R;
+            }
+            Class::EN if strong == Class::AL => *class = Class::AN,
+            _ => {}
+        }
+    }
+
+    // W4: a single separator between two numbers of the same kind joins them.
+    for i in 1..classes.len().saturating_sub(1) {
+        let (before, after) = (classes[i - 1], classes[i + 1]);
+        classes[i] = match (before, classes[i], after) {
+            (Class::EN, Class::ES | Class::CS, Class::EN) => Class::EN,
+            (Class::AN, Class::CS, Class::AN) => Class::AN,
+            _ => classes[i],
+        };
+    }
+
+    // W5: terminators next to European numbers become part of them.
+    let mut i = 0;
+    while i < classes.len() {
+        if classes[i] != Class::ET {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < classes.len() && classes[i] == Class::ET {
+            i += 1;
+        }
+        let before = start > 0 && classes[start - 1] == Class::EN;
+        if before || classes.get(i) == Some(&Class::EN) {
+            classes[start..i].fill(Class::EN);
+        }
+    }
+
+    // W6: other separators and terminators are neutral.
+    for class in &mut classes {
+        if matches!(class, Class::ES | Class::ET | Class::CS) {
+            *class = Class::ON;
+        }
+    }
+
+    // W7: European numbers in left to right text are left to right.
+    let mut strong = sos;
+    for class in &mut classes {
+        match *class {
+            Class::L | Class::R => strong = *class,
+            Class::EN if strong == Class::L => *class = Class::L,
+            _ => {}
+        }
+    }
+
+    // N1, N2: neutrals between text of the same direction take that direction, others
+    // take the direction of the paragraph.
+    let direction = |class: Class| match class {
+        Class::L => Some(Class::L),
+        Class::R | Class::EN | Class::AN => Some(Class::R),
+        _ => None,
+    };
+    let mut i = 0;
+    while i < classes.len() {
+        if direction(classes[i]).is_some() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < classes.len() && direction(classes[i]).is_none() {
+            i += 1;
+        }
+        let before = start
+            .checked_sub(1)
+            .map_or(sos, |j| direction(classes[j]).unwrap());
+        let after = classes.get(i).map_or(sos, |&c| direction(c).unwrap());
+        let resolved = if before == after { before } else { sos };
+        classes[start..i].fill(resolved);
+    }
+
+    // I1, I2: implicit levels.
+    let mut levels: Vec<u8> = classes
+        .iter()
+        .map(|&class| match (base, class) {
+            (0, Class::R) => 1,
+            (0, Class::AN | Class::EN) => 2,
+            (1, Class::L | Class::EN | Class::AN) => 2,
+            _ => base,
+        })
+        .collect();
+
+    // L1: trailing whitespace goes back to the paragraph level.
+    for (level, &c) in levels.iter_mut().zip(chars).rev() {
+        if class(c) != Class::WS {
+            break;
+        }
+        *level = base;
+    }
+    levels
+}
+
+/// The mirror image of a bracket or other paired character, for drawing it in right to
+/// left text.
+fn mirror(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '<' => '>',
+        '>' => '<',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '«' => '»',
+        '»' => '«',
+        '‹' => '›',
+        '›' => '‹',
+        _ => c,
+    }
+}
@@ -0,0 +1,403 @@
+use crate::{
+    page::Page,
+    raster::{luma, mix},
+    BitmapPixel, Error, Write,
+};
+use alloc::{vec, vec::Vec};
+
+/// Pixel layouts a BMP file can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum PixelFormat {
+    /// 24-bit color, 3 bytes per pixel.
+    #[default]
+    Bgr24,
+
+    /// 32-bit color with an (opaque) alpha channel, 4 bytes per pixel.
+    Bgra32,
+
+    /// 8-bit grayscale, 1 byte per pixel indexing into a palette of 256 grays.
+    Gray8,
+
+    /// 8-bit palette of 256 shades from the background to the text color. Pages
+    /// look the same as with [`PixelFormat::Bgr24`] at a third of the size.
+    ///
+    /// Pages with other colors, from a background image, a frame, illustrations or
+    /// highlights, get a palette of their exact colors instead, or are written as
+    /// [`PixelFormat::Bgr24`] if they have more than 256. The same goes for the other
+    /// palette formats. [`bmp_pixel_format`](crate::bmp_pixel_format) tells which one a
+    /// page gets.
+    Indexed8,
+
+    /// 4-bit palette of 16 shades from the background to the text color. Smooth
+    /// glyph edges get slightly coarser.
+    Indexed4,
+
+    /// 1-bit palette with only the background and text colors. Smooth glyph edges
+    /// are lost.
+    Indexed1,
+}
+
+impl PixelFormat {
+    fn bits_per_pixel(self) -> u16 {
+        match self {
+            PixelFormat::Bgr24 => 24,
+            PixelFormat::Bgra32 => 32,
+            PixelFormat::Gray8 | PixelFormat::Indexed8 => 8,
+            PixelFormat::Indexed4 => 4,
+            PixelFormat::Indexed1 => 1,
+        }
+    }
+
+    /// Number of palette entries following the headers.
+    fn colors(self) -> u32 {
+        match self {
+            PixelFormat::Bgr24 | PixelFormat::Bgra32 => 0,
+            _ => 1 << self.bits_per_pixel(),
+        }
+    }
+
+    fn is_indexed(self) -> bool {
+        matches!(
+            self,
+            PixelFormat::Indexed8 | PixelFormat::Indexed4 | PixelFormat::Indexed1
+        )
+    }
+
+    /// The palette for a page with the given colors.
+    fn palette(self, background: BitmapPixel, text: BitmapPixel) -> Vec<BitmapPixel> {
+        let colors = self.colors();
+        (0..colors)
+            .map(|i| {
+                let level = (i * 255 / (colors - 1)) as u8;
+                match self {
+                    PixelFormat::Gray8 => BitmapPixel(level, level, level),
+                    _ => BitmapPixel(
+                        mix(background.0, text.0, level),
+                        mix(background.1, text.1, level),
+                        mix(background.2, text.2, level),
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+/// BMP encoding options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BmpOptions {
+    /// How pixels are stored.
+    pub pixel_format: PixelFormat,
+
+    /// Resolution stored in the file, in dots per inch. It doesn't change the image,
+    /// but some programs use it to pick a default zoom level.
+    pub dpi: u32,
+
+    /// Store rows from the top of the image down, instead of the usual bottom-up order.
+    pub top_down: bool,
+}
+
+impl Default for BmpOptions {
+    fn default() -> Self {
+        Self {
+            pixel_format: PixelFormat::Bgr24,
+            dpi: 72,
+            top_down: false,
+        }
+    }
+}
+
+/// Size of the BITMAPFILEHEADER.
+const FILE_HEADER_SIZE: usize = 14;
+
+/// Size of the BITMAPINFOHEADER.
+const INFO_HEADER_SIZE: usize = 40;
+
+/// Size of the BITMAPV4HEADER, which adds channel masks to the BITMAPINFOHEADER.
+const V4_HEADER_SIZE: usize = 108;
+
+/// BI_RGB, uncompressed pixels.
+const COMPRESSION_RGB: u32 = 0;
+
+/// BI_BITFIELDS, uncompressed pixels with channel masks.
+const COMPRESSION_BITFIELDS: u32 = 3;
+
+/// The headers at the start of a BMP file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Header {
+    width: u32,
+    height: u32,
+    top_down: bool,
+    bits_per_pixel: u16,
+    /// Number of palette entries.
+    colors: u32,
+    /// Whether to use a V4 header with channel masks, needed to declare an alpha
+    /// channel.
+    bitfields: bool,
+    pixels_per_meter: u32,
+}
+
+impl Header {
+    fn new(
+        width: usize,
+        height: usize,
+        format: PixelFormat,
+        options: &BmpOptions,
+    ) -> Result<Self, Error> {
+        let too_large = Error::ImageTooLarge { width, height };
+        if width > i32::MAX as usize || height > i32::MAX as usize {
+            return Err(too_large);
+        }
+
+        let header = Self {
+            width: width as u32,
+            height: height as u32,
+            top_down: options.top_down,
+            bits_per_pixel: format.bits_per_pixel(),
+            colors: format.colors(),
+            bitfields: format == PixelFormat::Bgra32,
+            pixels_per_meter: ((options.dpi as u64 * 10_000 + 127) / 254).min(i32::MAX as u64)
+                as u32,
+        };
+        header
+            .stride()
+            .checked_mul(height)
+            .and_then(|size| size.checked_add(header.pixel_offset()))
+            .filter(|&size| size <= u32::MAX as usize)
+            .ok_or(too_large)?;
+        Ok(header)
+    }
+
+    fn info_header_size(&self) -> usize {
+        if self.bitfields {
+            V4_HEADER_SIZE
+        } else {
+            INFO_HEADER_SIZE
+        }
+    }
+
+    /// Offset of the pixel data from the start of the file.
+    fn pixel_offset(&self) -> usize {
+        FILE_HEADER_SIZE + self.info_header_size() + self.colors as usize * 4
+    }
+
+    /// Number of bytes per row. Rows are padded to a multiple of 4 bytes.
+    fn stride(&self) -> usize {
+        (self.width as usize * self.bits_per_pixel as usize).div_ceil(32) * 4
+    }
+
+    fn file_size(&self) -> usize {
+        self.pixel_offset() + self.stride() * self.height as usize
+    }
+
+    fn write<W: Write>(&self, palette: &[BitmapPixel], writer: &mut W) -> Result<(), Error> {
+        debug_assert_eq!(palette.len(), self.colors as usize);
+        let mut buf = [0; FILE_HEADER_SIZE + V4_HEADER_SIZE];
+        let mut at = 0;
+        let mut put = |bytes: &[u8]| {
+            buf[at..at + bytes.len()].copy_from_slice(bytes);
+            at += bytes.len();
+        };
+
+        // BITMAPFILEHEADER
+        put(b"BM");
+        put(&(self.file_size() as u32).to_le_bytes());
+        put(&[0; 4]); // reserved
+        put(&(self.pixel_offset() as u32).to_le_bytes());
+
+        // BITMAPINFOHEADER
+        let height = if self.top_down {
+            -(self.height as i32)
+        } else {
+            self.height as i32
+        };
+        put(&(self.info_header_size() as u32).to_le_bytes());
+        put(&(self.width as i32).to_le_bytes());
+        put(&height.to_le_bytes());
+        put(&1u16.to_le_bytes()); // planes
+        put(&self.bits_per_pixel.to_le_bytes());
+        put(&if self.bitfields {
+            COMPRESSION_BITFIELDS
+        } else {
+            COMPRESSION_RGB
+        }
+        .to_le_bytes());
+        put(&((self.stride() * self.height as usize) as u32).to_le_bytes());
+        put(&self.pixels_per_meter.to_le_bytes());
+        put(&self.pixels_per_meter.to_le_bytes());
+        put(&self.colors.to_le_bytes());
+        put(&0u32.to_le_bytes()); // important colors, 0 means all
+
+        if self.bitfields {
+            // BITMAPV4HEADER additions
+            put(&0x00FF0000u32.to_le_bytes()); // red mask
+            put(&0x0000FF00u32.to_le_bytes()); // green mask
+            put(&0x000000FFu32.to_le_bytes()); // blue mask
+            put(&0xFF000000u32.to_le_bytes()); // alpha mask
+            put(b"BGRs"); // LCS_sRGB, stored little-endian
+            put(&[0; 48]); // endpoints and gamma, unused for sRGB
+        }
+
+        writer.write_all(&buf[..at])?;
+
+        for color in palette {
+            writer.write_all(&[color.0, color.1, color.2, 0])?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the size of the BMP file for the page.
+pub(crate) fn encoded_size(page: &Page, options: &BmpOptions) -> Result<usize, Error> {
+    let (format, _) = resolve(page, options.pixel_format);
+    Ok(Header::new(page.width, page.height, format, options)?.file_size())
+}
+
+/// Get the pixel format the page is written in.
+pub(crate) fn pixel_format(page: &Page, options: &BmpOptions) -> PixelFormat {
+    resolve(page, options.pixel_format).0
+}
+
+/// Encode the page as a BMP file.
+pub(crate) fn encode<W: Write>(
+    page: &Page,
+    options: &BmpOptions,
+    writer: &mut W,
+) -> Result<(), Error> {
+    let (format, colors) = resolve(page, options.pixel_format);
+    let header = Header::new(page.width, page.height, format, options)?;
+    let palette = match &colors {
+        Some(Colors::Exact(colors)) => (0..format.colors() as usize)
+            .map(|i| {
+                colors
+                    .get(i)
+                    .map_or(BitmapPixel(0, 0, 0), |&[b, g, r]| BitmapPixel(b, g, r))
+            })
+            .collect(),
+        _ => format.palette(page.background_color, page.text_color),
+    };
+    header.write(&palette, writer)?;
+
+    let mut row = vec![0; header.stride()];
+    page.rows(!options.top_down, |pixels| {
+        convert_row(format, colors.as_ref(), pixels, &mut row);
+        writer.write_all(&row)
+    })
+}
+
+/// How the pixels of a page in a palette format map to palette entries.
+enum Colors<'a> {
+    /// Shades between the background and text colors.
+    Ramp(Ramp),
+    /// The exact colors of the page, sorted.
+    Exact(&'a [[u8; 3]]),
+}
+
+impl Colors<'_> {
+    /// Palette index in `0..=last` of the color of a BGR pixel.
+    fn index(&self, pixel: &[u8], last: u8) -> u8 {
+        match self {
+            Colors::Ramp(ramp) => ramp.index(pixel, last),
+            Colors::Exact(colors) => {
+                let pixel = [pixel[0], pixel[1], pixel[2]];
+                colors.binary_search(&pixel).unwrap_or(0) as u8
+            }
+        }
+    }
+}
+
+/// The format the page is written in and, for palette formats, how its colors are
+/// stored. Pages drawn in only the text and background colors use a ramp between them,
+/// others a palette of their exact colors, or 24-bit color if there are too many.
+fn resolve<'p>(page: &'p Page, format: PixelFormat) -> (PixelFormat, Option<Colors<'p>>) {
+    if !format.is_indexed() {
+        return (format, None);
+    }
+    if page.is_two_tone() {
+        let ramp = Ramp::new(page.background_color, page.text_color);
+        return (format, Some(Colors::Ramp(ramp)));
+    }
+    match page.colors() {
+        Some(colors) if colors.len() <= format.colors() as usize => {
+            (format, Some(Colors::Exact(colors)))
+        }
+        _ => (PixelFormat::Bgr24, None),
+    }
+}
+
+/// Convert a row of 24-bit BGR pixels to the given format. Any padding at the end of
+/// `out` is left untouched.
+fn convert_row(format: PixelFormat, colors: Option<&Colors>, pixels: &[u8], out: &mut [u8]) {
+    match format {
+        PixelFormat::Bgr24 => out[..pixels.len()].copy_from_slice(pixels),
+        PixelFormat::Bgra32 => {
+            for (out, pixel) in out.chunks_exact_mut(4).zip(pixels.chunks_exact(3)) {
+                out.copy_from_slice(&[pixel[0], pixel[1], pixel[2], 0xFF]);
+            }
+        }
+        PixelFormat::Gray8 => {
+            for (out, pixel) in out.iter_mut().zip(pixels.chunks_exact(3)) {
+                *out = luma(pixel);
+            }
+        }
+        PixelFormat::Indexed8 | PixelFormat::Indexed4 | PixelFormat::Indexed1 => {
+            let Some(colors) = colors else {
+                return;
+            };
+            let bits = format.bits_per_pixel() as usize;
+            let per_byte = 8 / bits;
+            let last = ((1u16 << bits) - 1) as u8;
+            for (out, pixels) in out.iter_mut().zip(pixels.chunks(3 * per_byte)) {
+                // The leftmost pixel goes in the most significant bits.
+                *out = pixels
+                    .chunks_exact(3)
+                    .enumerate()
+                    .fold(0, |byte, (i, pixel)| {
+                        byte | colors.index(pixel, last) << (8 - bits * (i + 1))
+                    });
+            }
+        }
+    }
+}
+
+/// The line between the background and text colors that palettes are built on.
+struct Ramp {
+    background: [i32; 3],
+    delta: [i32; 3],
+    /// Squared length of `delta`.
+    length: i32,
+}
+
+impl Ramp {
+    fn new(background: BitmapPixel, text: BitmapPixel) -> Self {
+        let background = [
+            background.0 as i32,
+            background.1 as i32,
+            background.2 as i32,
+        ];
+        let text = [text.0 as i32, text.1 as i32, text.2 as i32];
+        let delta = [
+            text[0] - background[0],
+            text[1] - background[1],
+            text[2] - background[2],
+        ];
+        Self {
+            background,
+            delta,
+            length: delta.iter().map(|d| d * d).sum(),
+        }
+    }
+
+    /// Palette index in `0..=last` of the shade closest to a BGR pixel.
+    fn index(&self, pixel: &[u8], last: u8) -> u8 {
+        if self.length == 0 {
+            return 0;
+        }
+        // Project the pixel onto the ramp.
+        let dot: i32 = (0..3)
+            .map(|i| (pixel[i] as i32 - self.background[i]) * self.delta[i])
+            .sum();
+        let index = (dot as i64 * last as i64 + self.length as i64 / 2) / self.length as i64;
+        index.clamp(0, last as i64) as u8
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{
+    fonts::{Font, SUBPIXELS},
+    raster::{blit_row, Canvas},
+    split_color, Background, BitmapPixel, Decoration, Fit, Frame, Span,
+};
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+/// Draw the background image over the part of a page of the given size covered by the
+/// canvas.
+pub(crate) fn draw_background(
+    canvas: &mut Canvas,
+    background: &Background,
+    (width, height): (usize, usize),
+) {
+    let (image_width, image_height) = (background.width, background.height);
+    let pixel = |x: usize, y: usize| &background.pixels[(y * image_width + x) * 3..][..3];
+    match background.fit {
+        Fit::Tile => {
+            for y in canvas.rows() {
+                let src = &background.pixels[(y % image_height) * image_width * 3..];
+                let src = &src[..image_width * 3];
+                for chunk in canvas.row(y).chunks_mut(src.len()) {
+                    chunk.copy_from_slice(&src[..chunk.len()]);
+                }
+            }
+        }
+        Fit::Stretch => {
+            // Pixel centers of the page are mapped onto the image, each landing between
+            // two columns and two rows that are mixed by how close they are.
+            let columns = samples(image_width, width);
+            for y in canvas.rows() {
+                let (top, bottom, v) = sample(y, image_height, height);
+                let row = canvas.row(y);
+                for (out, &(left, right, u)) in row.chunks_exact_mut(3).zip(&columns) {
+                    let (a, b) = (pixel(left, top), pixel(right, top));
+                    let (c, d) = (pixel(left, bottom), pixel(right, bottom));
+                    for i in 0..3 {
+                        let upper = lerp(a[i] as u32, b[i] as u32, u);
+                        let lower = lerp(c[i] as u32, d[i] as u32, u);
+                        out[i] = ((lerp(upper, lower, v) + (1 << 15)) >> 16) as u8;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Weight of the second value out of 256.
+fn lerp(a: u32, b: u32, weight: u32) -> u32 {
+    a * (256 - weight) + b * weight
+}
+
+/// For each of the `len` pixels along an axis of the page, see [`sample`].
+fn samples(image_len: usize, len: usize) -> Vec<(usize, usize, u32)> {
+    (0..len).map(|i| sample(i, image_len, len)).collect()
+}
+
+/// The two pixels of the image a pixel of the page falls between, and how far it is
+/// toward the second, out of 256.
+fn sample(i: usize, image_len: usize, len: usize) -> (usize, usize, u32) {
+    let position = (i as f32 + 0.5) * image_len as f32 / len as f32 - 0.5;
+    let position = position.clamp(0.0, (image_len - 1) as f32);
+    let first = position as usize;
+    let weight = ((position - first as f32) * 256.0 + 0.5) as u32;
+    (first, (first + 1).min(image_len - 1), weight)
+}
+
+/// Draw the frame over the part of a page of the given size covered by the canvas.
+pub(crate) fn draw_frame(canvas: &mut Canvas, frame: &Frame, (width, height): (usize, usize)) {
+    let outer = RoundedRect::new(
+        (frame.inset, frame.inset),
+        (width as f32 - frame.inset, height as f32 - frame.inset),
+        frame.radius,
+    );
+    let Some(outer) = outer else {
+        return;
+    };
+    let inner = outer.shrink(frame.thickness);
+
+    // Pixels past the edges are covered a little when the edges fall between pixels.
+    let columns = outer.left as usize..(outer.right as usize + 1).min(width);
+    let rows = outer.top as usize..(outer.bottom as usize + 1).min(height);
+    let rows = rows.start.max(canvas.rows().start)..rows.end.min(canvas.rows().end);
+    let mut fill = vec![0; columns.len()];
+    let mut outline = vec![0; columns.len()];
+    for y in rows {
+        for (i, x) in columns.clone().enumerate() {
+            let point = (x as f32 + 0.5, y as f32 + 0.5);
+            let coverage = outer.coverage(point);
+            let inside = inner.map_or(0.0, |inner| inner.coverage(point));
+            fill[i] = (coverage * 255.0 + 0.5) as u8;
+            outline[i] = ((coverage - inside).max(0.0) * 255.0 + 0.5) as u8;
+        }
+        let row = &mut canvas.row(y)[columns.start * 3..columns.end * 3];
+        if let Some(color) = frame.fill {
+            blit_row(row, &fill, split_color(color));
+        }
+        if frame.thickness > 0.0 {
+            blit_row(row, &outline, split_color(frame.color));
+        }
+    }
+}
+
+/// A rectangle with rounded corners, in pixels.
+#[derive(Debug, Clone, Copy)]
+struct RoundedRect {
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+    radius: f32,
+}
+
+impl RoundedRect {
+    /// The rectangle between two corners, unless it is empty. The radius is limited to
+    /// half of the shorter side.
+    fn new((left, top): (f32, f32), (right, bottom): (f32, f32), radius: f32) -> Option<Self> {
+        if right <= left || bottom <= top {
+            return None;
+        }
+        let radius = radius.min((right - left) / 2.0).min((bottom - top) / 2.0);
+        Some(Self {
+            left,
+            top,
+            right,
+            bottom,
+            radius,
+        })
+    }
+
+    /// The rectangle with each side moved in by `by`, keeping the corners concentric.
+    fn shrink(&self, by: f32) -> Option<Self> {
+        Self::new(
+            (self.left + by, self.top + by),
+            (self.right - by, self.bottom - by),
+            (self.radius - by).max(0.0),
+        )
+    }
+
+    /// How much of a pixel centered on the point is inside, from 0 to 1, going by its
+    /// distance from the edge.
+    fn coverage(&self, (x, y): (f32, f32)) -> f32 {
+        let (half_width, half_height) = (
+            (self.right - self.left) / 2.0,
+            (self.bottom - self.top) / 2.0,
+        );
+        let dx = (x - self.left - half_width).abs() - half_width + self.radius;
+        let dy = (y - self.top - half_height).abs() - half_height + self.radius;
+        let outside = sqrt(dx.max(0.0) * dx.max(0.0) + dy.max(0.0) * dy.max(0.0));
+        let distance = outside + dx.max(dy).min(0.0) - self.radius;
+        (0.5 - distance).clamp(0.0, 1.0)
+    }
+}
+
+/// A decoration along part of a line of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Mark {
+    /// Index of the line on the page.
+    pub line: usize,
+    /// Where the decoration starts and ends across the page, in 1/64 of a pixel.
+    pub span: Range<usize>,
+    pub decoration: Decoration,
+}
+
+/// Find the parts of a line drawn from `x` that the spans cover. The text is in the
+/// order it is drawn in, and `origins` holds the offset in the text passed in of each
+/// of its characters. A span covering characters apart from each other, as it may in
+/// bidirectional text, is split into several marks.
+pub(crate) fn find_marks(
+    (line, text, x): (usize, &str, usize),
+    origins: &[usize],
+    font: &Font,
+    spans: &[Span],
+    marks: &mut Vec<Mark>,
+) {
+    for span in spans {
+        let mut pen = x;
+        let mut start = None;
+        for (c, origin) in text.chars().zip(origins) {
+            match (span.range.contains(origin), start) {
+                (true, None) => start = Some(pen),
+                (false, Some(left)) => {
+                    marks.push(Mark {
+                        line,
+                        span: left..pen,
+                        decoration: span.decoration,
+                    });
+                    start = None;
+                }
+                _ => {}
+            }
+            pen += font.advance(c);
+        }
+        if let Some(left) = start {
+            marks.push(Mark {
+                line,
+                span: left..pen,
+                decoration: span.decoration,
+            });
+        }
+    }
+}
+
+/// Columns of whole pixels a mark covers, up to `max_x`.
+fn columns(mark: &Mark, max_x: usize) -> Range<usize> {
+    let pixel = |x: usize| ((x + SUBPIXELS / 2) / SUBPIXELS).min(max_x);
+    pixel(mark.span.start)..pixel(mark.span.end)
+}
+
+/// Draw the highlights of a line with its top at `y`, which go behind the text.
+pub(crate) fn draw_highlights(
+    canvas: &mut Canvas,
+    marks: &[Mark],
+    (y, max_x): (usize, usize),
+    line_height: usize,
+) {
+    for mark in marks {
+        if let Decoration::Highlight(color) = mark.decoration {
+            let rows = y..y + line_height;
+            canvas.fill_rect(columns(mark, max_x), rows, split_color(color));
+        }
+    }
+}
+
+/// Draw the underlines and strikethroughs of a line with its top at `y`, which go over
+/// the text.
+pub(crate) fn draw_lines(
+    canvas: &mut Canvas,
+    marks: &[Mark],
+    (y, max_x): (usize, usize),
+    font: &Font,
+    color: BitmapPixel,
+) {
+    for mark in marks {
+        let (top, thickness) = match mark.decoration {
+            Decoration::Underline => font.underline(),
+            Decoration::Strikethrough => font.strikethrough(),
+            Decoration::Highlight(_) => continue,
+        };
+        let rows = y + top..y + top + thickness;
+        canvas.fill_rect(columns(mark, max_x), rows, color);
+    }
+}
+
+/// Square root by Newton's method, which is plenty precise for antialiasing.
+fn sqrt(x: f32) -> f32 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    // Halving the exponent gives a first guess within a few percent.
+    let mut y = f32::from_bits((x.to_bits() >> 1) + 0x1FBD_1DF5);
+    for _ in 0..3 {
+        y = 0.5 * (y + x / y);
+    }
+    y
+}
@@ -0,0 +1,208 @@
+use crate::{Frame, Margins};
+use core::fmt;
+
+/// Errors that can occur while laying out or rendering text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// There is no text to render.
+    EmptyText,
+
+    /// The text contains a character the font has no glyph for.
+    UnsupportedCharacter(char),
+
+    /// The font or its bitmaps are less than a pixel tall, it is scaled by more than 16
+    /// times, or its height or scale is not a finite number.
+    InvalidFontSize(f32),
+
+    /// The page width is zero, negative or not a number.
+    InvalidWidth(f32),
+
+    /// A side of the padding is negative or not a number.
+    InvalidPadding(Margins),
+
+    /// The horizontal padding leaves no room for text, or for a column of vertical text.
+    PaddingTooLarge {
+        /// The left and right padding together.
+        padding: f32,
+        /// The page width.
+        width: f32,
+    },
+
+    /// The number of lines per page is zero.
+    ZeroLines,
+
+    /// The number of columns is zero.
+    ZeroColumns,
+
+    /// The gutter between columns is negative, not a number, or leaves no room for text.
+    InvalidGutter(f32),
+
+    /// The page height cannot fit a single line of text, below the title if there is
+    /// one.
+    PageTooShort {
+        /// The requested page height.
+        page_height: f32,
+        /// The height needed for one line of text, including padding and the title.
+        required: f32,
+    },
+
+    /// Vertical text is paginated by the page height, but none was given.
+    NoPageHeight,
+
+    /// The paragraph spacing is negative or not a number.
+    InvalidParagraphSpacing(f32),
+
+    /// The space below the title is negative or not a number.
+    InvalidTitleSpacing(f32),
+
+    /// The title is taller than the lines of a page, which has no height to check it
+    /// against.
+    TitleTooTall {
+        /// The height of the title with its spacing.
+        height: usize,
+        /// The height of the lines of a page.
+        max: usize,
+    },
+
+    /// The background image is empty or has fewer pixels than its size calls for.
+    InvalidBackground {
+        /// The image width.
+        width: usize,
+        /// The image height.
+        height: usize,
+        /// The number of bytes of pixels.
+        len: usize,
+    },
+
+    /// An illustration is empty or has fewer pixels than its size calls for.
+    InvalidIllustration {
+        /// The index of the illustration.
+        index: usize,
+        /// The image width.
+        width: usize,
+        /// The image height.
+        height: usize,
+        /// The number of bytes of pixels.
+        len: usize,
+    },
+
+    /// The inset, thickness or radius of the frame is negative or not a number.
+    InvalidFrame(Frame),
+
+    /// The drop cap spans so many lines that its letter would be scaled by more than 16
+    /// times.
+    DropCapTooLarge(usize),
+
+    /// The requested page does not exist.
+    PageOutOfRange {
+        /// The requested page.
+        page: usize,
+        /// The number of pages the text was split into.
+        pages: usize,
+    },
+
+    /// The image is too large to be encoded.
+    ImageTooLarge {
+        /// The image width.
+        width: usize,
+        /// The image height.
+        height: usize,
+    },
+
+    /// The buffer is too small to hold the image.
+    BufferTooSmall {
+        /// The number of bytes needed.
+        required: usize,
+        /// The size of the buffer.
+        len: usize,
+    },
+
+    /// A [`Write`](crate::Write) destination failed to take the image.
+    Write,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::EmptyText => write!(f, "there is no text to render"),
+            Error::UnsupportedCharacter(c) => {
+                write!(f, "character {c:?} (U+{:04X}) is not supported", c as u32)
+            }
+            Error::InvalidFontSize(size) => write!(f, "invalid font size {size}"),
+            Error::InvalidWidth(width) => write!(f, "invalid page width {width}"),
+            Error::InvalidPadding(Margins {
+                top,
+                right,
+                bottom,
+                left,
+            }) => write!(f, "invalid padding ({top}, {right}, {bottom}, {left})"),
+            Error::PaddingTooLarge { padding, width } => write!(
+                f,
+                "horizontal padding {padding} leaves no room for text in a page of width {width}"
+            ),
+            Error::ZeroLines => write!(f, "the number of lines per page must not be zero"),
+            Error::ZeroColumns => write!(f, "the number of columns must not be zero"),
+            Error::InvalidGutter(gutter) => write!(f, "invalid column gutter {gutter}"),
+            Error::PageTooShort {
+                page_height,
+                required,
+            } => write!(
+                f,
+                "page height {page_height} is too short, at least {required} is needed for one line"
+            ),
+            Error::NoPageHeight => write!(f, "vertical text needs a page height"),
+            Error::InvalidParagraphSpacing(spacing) => {
+                write!(f, "invalid paragraph spacing {spacing}")
+            }
+            Error::InvalidTitleSpacing(spacing) => write!(f, "invalid title spacing {spacing}"),
+            Error::TitleTooTall { height, max } => write!(
+                f,
+                "a title of height {height} is taller than the {max} of the lines of a page"
+            ),
+            Error::InvalidBackground { width, height, len } => write!(
+                f,
+                "a {width}x{height} background image needs {} bytes but only has {len}",
+                width.saturating_mul(height).saturating_mul(3)
+            ),
+            Error::InvalidIllustration {
+                index,
+                width,
+                height,
+                len,
+            } => write!(
+                f,
+                "illustration {index} of {width}x{height} needs {} bytes but only has {len}",
+                width.saturating_mul(height).saturating_mul(3)
+            ),
+            Error::InvalidFrame(Frame {
+                inset,
+                thickness,
+                radius,
+                ..
+            }) => write!(
+                f,
+                "invalid frame with inset {inset}, thickness {thickness} and radius {radius}"
+            ),
+            Error::DropCapTooLarge(lines) => {
+                write!(f, "a drop cap of {lines} lines is too large for the font")
+            }
+            Error::PageOutOfRange { page, pages } => {
+                write!(
+                    f,
+                    "page {page} is out of range, the text only has {pages} pages"
+                )
+            }
+            Error::ImageTooLarge { width, height } => {
+                write!(f, "a {width}x{height} image is too large to be encoded")
+            }
+            Error::BufferTooSmall { required, len } => write!(
+                f,
+                "the image needs {required} bytes but the buffer only has {len}"
+            ),
+            Error::Write => write!(f, "failed to write the image"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -88,13 +88,13 @@ public class FontToRust {
             }
 
 
-            fw.write("pub const " + constName + size + ":Font=Font{height:" + fontHeight(fontf) + ".0,font:[");
+            fw.write("pub const " + constName + size + ":Font=Font{height:" + fontHeight(fontf) + ".0,scale:1.0,font:[");
             for (int[] character : font) {
                 fw.write("&");
                 fw.write(Arrays.toString(character).replace("\s", ""));
                 fw.write(",");
             }
-            fw.write("]};");
+            fw.write("],extra:&[]};");
         }
         fw.write("\n");
         fw.close();
@@ -13,13 +13,145 @@ mod data {
 #[cfg_attr(not(test), cfg(feature = "fonts"))]
 pub use data::*;
 
-#[derive(Debug, Clone, Copy)]
+/// Horizontal positions and advances are measured in 1/64 of a pixel, so that glyphs
+/// can be placed between pixels.
+pub(crate) const SUBPIXELS: usize = 64;
+
+/// Largest factor a font can be scaled by.
+pub(crate) const MAX_SCALE: f32 = 16.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Font {
     pub height: f32,
+    /// Factor the glyphs are resampled by when drawn, `1.0` to draw them as they are.
+    /// See [`Font::scaled`].
+    pub scale: f32,
     pub font: [&'static [u8]; 95],
+    /// Glyphs for characters beyond printable ASCII, sorted by character. The built-in
+    /// fonts have none.
+    pub extra: &'static [(char, &'static [u8])],
 }
 
 impl Font {
+    /// Get a version of this font with lines of the given height in pixels. The glyphs
+    /// are resampled when drawn: averaged down when shrinking, with a bicubic filter
+    /// when growing.
+    ///
+    /// Resampling works best from a close size, see [`Font::from_family`].
+    pub fn scaled(self, height: f32) -> Self {
+        Self {
+            scale: height / self.height,
+            ..self
+        }
+    }
+
+    /// Height of a line of text in pixels, once scaled.
+    pub(crate) fn line_height(&self) -> usize {
+        (self.height * self.scale + 0.5) as usize
+    }
+
+    /// Height of the glyph bitmaps.
+    pub(crate) fn bitmap_height(&self) -> usize {
+        self.height as usize
+    }
+
+    /// Whether the glyphs are drawn as they are. A fractional height rounds to a line
+    /// with more rows than the bitmaps have, so they are resampled to it.
+    pub(crate) fn is_unscaled(&self) -> bool {
+        self.scale == 1.0 && self.line_height() == self.bitmap_height()
+    }
+
+    /// Whether the font can be drawn: its height is finite and leaves the bitmaps and
+    /// lines at least a row, and it is scaled by more than 0 and at most 16 times.
+    pub(crate) fn is_drawable(&self) -> bool {
+        let scaled = self.scale > 0.0 && self.scale <= MAX_SCALE;
+        scaled && self.height.is_finite() && self.bitmap_height() > 0 && self.line_height() > 0
+    }
+
+    /// Whether the font has a glyph for the given character.
+    pub(crate) fn has_glyph(&self, c: char) -> bool {
+        (' '..='~').contains(&c) || self.extra_glyph(c).is_some()
+    }
+
+    fn extra_glyph(&self, c: char) -> Option<&'static [u8]> {
+        self.extra
+            .binary_search_by_key(&c, |&(c, _)| c)
+            .ok()
+            .map(|i| self.extra[i].1)
+    }
+
+    /// Bitmap of the glyph for the given character. Characters the font has no glyph
+    /// for are drawn as spaces.
+    pub(crate) fn glyph(&self, c: char) -> &'static [u8] {
+        if (' '..='~').contains(&c) {
+            return self.font[c as usize - ' ' as usize];
+        }
+        self.extra_glyph(c).unwrap_or(self.font[0])
+    }
+
+    /// Width of the bitmap of the glyph for the given character in pixels.
+    pub(crate) fn glyph_width(&self, c: char) -> usize {
+        self.glyph(c).len() / self.bitmap_height()
+    }
+
+    /// Width of the glyph for the given character in pixels, once scaled.
+    pub(crate) fn scaled_width(&self, c: char) -> usize {
+        (self.glyph_width(c) as f32 * self.scale + 0.5) as usize
+    }
+
+    /// Distance from the glyph for the given character to the next one, in 1/64 of a
+    /// pixel.
+    pub(crate) fn advance(&self, c: char) -> usize {
+        if self.is_unscaled() {
+            return self.glyph_width(c) * SUBPIXELS;
+        }
+        (self.glyph_width(c) as f32 * self.scale * SUBPIXELS as f32 + 0.5) as usize
+    }
+
+    /// First and last rows of the ink of the glyph for the given character, in pixels
+    /// from the top of the line once scaled, unless it has none.
+    pub(crate) fn ink_rows(&self, c: char) -> Option<(usize, usize)> {
+        let width = self.glyph_width(c);
+        let mut inked = self
+            .glyph(c)
+            .chunks_exact(width.max(1))
+            .enumerate()
+            .filter(|(_, row)| row.iter().any(|&a| a > 0))
+            .map(|(y, _)| y);
+        let first = inked.next()?;
+        let last = inked.next_back().unwrap_or(first) + 1;
+        let scale = |y: usize| (y as f32 * self.scale + 0.5) as usize;
+        Some((scale(first), scale(last))).filter(|(top, bottom)| top < bottom)
+    }
+
+    /// Top of an underline and its thickness in pixels, once scaled. It sits one stroke
+    /// of a hyphen below the baseline of `H`.
+    pub(crate) fn underline(&self) -> (usize, usize) {
+        let line_height = self.line_height();
+        let thickness = self.stroke();
+        let baseline = self
+            .ink_rows('H')
+            .map_or(line_height * 4 / 5, |(_, bottom)| bottom);
+        let top = (baseline + thickness).min(line_height.saturating_sub(thickness));
+        (top, thickness)
+    }
+
+    /// Top of a strikethrough and its thickness in pixels, once scaled. It is drawn over
+    /// the ink of a hyphen.
+    pub(crate) fn strikethrough(&self) -> (usize, usize) {
+        match self.ink_rows('-') {
+            Some((top, bottom)) => (top, bottom - top),
+            None => (self.line_height() / 2, self.stroke()),
+        }
+    }
+
+    /// Thickness of the strokes of a hyphen, at least a pixel.
+    fn stroke(&self) -> usize {
+        self.ink_rows('-')
+            .map_or(self.line_height() / 16, |(top, bottom)| bottom - top)
+            .max(1)
+    }
+
     /// Get a font from its name.
     #[cfg_attr(not(test), cfg(feature = "fonts"))]
     pub fn from_name<T: AsRef<str>>(name: T) -> Self {
@@ -51,4 +183,57 @@ impl Font {
             _ => times::TIMES18,
         }
     }
+
+    /// Get a font from its family name with lines of the given height in pixels. The
+    /// glyphs are resampled from the closest larger built-in size, or the largest one.
+    #[cfg_attr(not(test), cfg(feature = "fonts"))]
+    pub fn from_family<T: AsRef<str>>(family: T, height: f32) -> Self {
+        let sizes = match family.as_ref() {
+            "arial" => [
+                arial::ARIAL18,
+                arial::ARIAL24,
+                arial::ARIAL30,
+                arial::ARIAL36,
+            ],
+            "atkinson" => [
+                atkinson::ATKINSON18,
+                atkinson::ATKINSON24,
+                atkinson::ATKINSON30,
+                atkinson::ATKINSON36,
+            ],
+            "fs_brabo" => [
+                fs_brabo::FS_BRABO18,
+                fs_brabo::FS_BRABO24,
+                fs_brabo::FS_BRABO30,
+                fs_brabo::FS_BRABO36,
+            ],
+            "georgia" => [
+                georgia::GEORGIA18,
+                georgia::GEORGIA24,
+                georgia::GEORGIA30,
+                georgia::GEORGIA36,
+            ],
+            "palatino" => [
+                palatino::PALATINO18,
+                palatino::PALATINO24,
+                palatino::PALATINO30,
+                palatino::PALATINO36,
+            ],
+            _ => [
+                times::TIMES18,
+                times::TIMES24,
+                times::TIMES30,
+                times::TIMES36,
+            ],
+        };
+        let font = sizes
+            .into_iter()
+            .find(|font| font.height >= height)
+            .unwrap_or(sizes[3]);
+        if font.height == height {
+            font
+        } else {
+            font.scaled(height)
+        }
+    }
 }
@@ -0,0 +1,90 @@
+use crate::{
+    fonts::SUBPIXELS,
+    raster::{mix, Canvas, Glyphs},
+    BitmapPixel, RunningHead,
+};
+use alloc::{format, string::String, vec::Vec};
+use core::ops::Range;
+
+/// A running header or footer laid out on a page.
+pub(crate) struct Head<'a> {
+    glyphs: Glyphs<'a>,
+    /// Each piece of text with its left edge, in 1/64 of a pixel.
+    texts: Vec<(String, usize)>,
+    /// The text area the head spans.
+    columns: Range<usize>,
+    top: usize,
+    /// Rows of the progress bar and how far it is filled.
+    bar: Option<(Range<usize>, usize)>,
+}
+
+impl<'a> Head<'a> {
+    /// Lay out the head centered in the given rows, across the given columns, for
+    /// page `page` of `pages`.
+    pub fn new(
+        head: &'a RunningHead,
+        columns: Range<usize>,
+        rows: Range<usize>,
+        (page, pages): (usize, usize),
+    ) -> Self {
+        let font = &head.font;
+        let top = rows.start + rows.len().saturating_sub(head.height()) / 2;
+        let progress = page as f32 / pages.max(1) as f32;
+        let fill = |template: &str| {
+            template
+                .replace("{pages}", &format!("{pages}"))
+                .replace("{page}", &format!("{page}"))
+                .replace(
+                    "{percent}",
+                    &format!("{}", (progress * 100.0 + 0.5) as usize),
+                )
+        };
+
+        let mut glyphs = Glyphs::new(font);
+        let mut texts = Vec::new();
+        let (left, right) = (columns.start * SUBPIXELS, columns.end * SUBPIXELS);
+        // Alignments in halves of the free space: left, centered and right.
+        for (template, align) in [(head.left, 0), (head.center, 1), (head.right, 2)] {
+            if template.is_empty() {
+                continue;
+            }
+            let text = fill(template);
+            let advance = text.chars().map(|c| font.advance(c)).sum::<usize>();
+            let x = left + (right - left).saturating_sub(advance) * align / 2;
+            glyphs.prepare(&text, x);
+            texts.push((text, x));
+        }
+
+        let bar = head.progress_bar.then(|| {
+            let bar_top = top + head.height() - head.bar_height();
+            let filled = columns.start + (columns.len() as f32 * progress + 0.5) as usize;
+            (
+                bar_top..bar_top + head.bar_height(),
+                filled.min(columns.end),
+            )
+        });
+        Self {
+            glyphs,
+            texts,
+            columns,
+            top,
+            bar,
+        }
+    }
+
+    pub fn draw(&self, canvas: &mut Canvas, color: BitmapPixel, background: BitmapPixel) {
+        for (text, x) in &self.texts {
+            canvas.draw_text(text, (*x, self.top), self.columns.end, &self.glyphs, color);
+        }
+        if let Some((rows, filled)) = &self.bar {
+            // The rest of the bar is a faint track.
+            let track = BitmapPixel(
+                mix(background.0, color.0, 48),
+                mix(background.1, color.1, 48),
+                mix(background.2, color.2, 48),
+            );
+            canvas.fill_rect(self.columns.start..*filled, rows.clone(), color);
+            canvas.fill_rect(*filled..self.columns.end, rows.clone(), track);
+        }
+    }
+}
@@ -0,0 +1,625 @@
+use crate::{
+    bidi, filter_text,
+    fonts::{Font, SUBPIXELS},
+    linebreak, typography, vertical, Illustration, ImageOptions, RunningHead, Title, WritingMode,
+};
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+/// Page metrics in whole pixels.
+///
+/// All fractional values from [`ImageOptions`] are converted here, once, so that the
+/// layout and rasterization never have to deal with floating point positions. Widths
+/// and padding are rounded up so the requested margins are always honored, the page
+/// height is rounded down so pages never exceed it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Metrics {
+    /// Page width, used as-is when `constant_width` is set.
+    pub width: usize,
+    pub padding_top: usize,
+    pub padding_right: usize,
+    pub padding_bottom: usize,
+    pub padding_left: usize,
+    pub line_height: usize,
+    pub paragraph_spacing: usize,
+    pub page_height: Option<usize>,
+    pub lines: usize,
+    pub orphans: usize,
+    pub widows: usize,
+    /// Number of columns of text, at least 1.
+    pub columns: usize,
+    /// Space between columns.
+    pub gutter: usize,
+    /// Height of the title and the space below it, on the first page.
+    pub title_height: usize,
+    /// Drop cap of the first paragraph, set once the text is known.
+    pub drop_cap: Option<DropCap>,
+}
+
+impl Metrics {
+    pub fn new(font: &Font, options: &ImageOptions) -> Self {
+        // Running heads are centered in the padding, which grows to fit them.
+        let head = |head: &Option<RunningHead>| head.as_ref().map_or(0, |h| h.height() * 2);
+        Self {
+            width: ceil(options.width),
+            padding_top: ceil(options.padding.top).max(head(&options.header)),
+            padding_right: ceil(options.padding.right),
+            padding_bottom: ceil(options.padding.bottom).max(head(&options.footer)),
+            padding_left: ceil(options.padding.left),
+            line_height: font.line_height(),
+            paragraph_spacing: round(options.paragraph_spacing),
+            page_height: options.page_height.map(floor),
+            lines: options.lines,
+            orphans: options.orphans,
+            widows: options.widows,
+            columns: options.columns.max(1),
+            gutter: round(options.gutter),
+            title_height: 0,
+            drop_cap: None,
+        }
+        .with_title(options.title.as_ref())
+    }
+
+    fn with_title(self, title: Option<&Title>) -> Self {
+        let title_height = title.map_or(0, |title| {
+            let lines = title_lines(title, self.text_width()).len();
+            (lines * title.font.line_height()).saturating_add(round(title.spacing))
+        });
+        Self {
+            title_height,
+            ..self
+        }
+    }
+
+    /// Maximum width of a line of text.
+    pub fn text_width(&self) -> usize {
+        self.width
+            .saturating_sub(self.padding_left + self.padding_right)
+    }
+
+    /// Width of each column of text.
+    pub fn column_width(&self) -> usize {
+        let gutters = self.gutter * (self.columns - 1);
+        self.text_width().saturating_sub(gutters) / self.columns
+    }
+
+    /// Left edge of the given column.
+    pub fn column_left(&self, column: usize) -> usize {
+        self.padding_left + column * (self.column_width() + self.gutter)
+    }
+
+    /// Total vertical padding.
+    pub fn padding_height(&self) -> usize {
+        self.padding_top + self.padding_bottom
+    }
+
+    /// The metrics with the page turned on its side, for stacking the columns of vertical
+    /// text across it the way lines are stacked down it, starting from the right. The
+    /// page must have a height.
+    pub fn across(&self) -> Self {
+        Self {
+            width: self.page_height.unwrap_or(0),
+            page_height: Some(self.width),
+            padding_top: self.padding_right,
+            padding_right: self.padding_bottom,
+            padding_bottom: self.padding_left,
+            padding_left: self.padding_top,
+            columns: 1,
+            gutter: 0,
+            title_height: 0,
+            drop_cap: None,
+            ..*self
+        }
+    }
+}
+
+// Conversions from option values to pixels. Negative and NaN values become zero.
+
+fn floor(value: f32) -> usize {
+    value.max(0.0) as usize
+}
+
+fn ceil(value: f32) -> usize {
+    let floor = floor(value);
+    if (floor as f32) < value {
+        floor + 1
+    } else {
+        floor
+    }
+}
+
+fn round(value: f32) -> usize {
+    floor(value + 0.5)
+}
+
+/// A large first letter sunk into the first lines of the text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DropCap {
+    pub letter: char,
+    /// The font scaled for the letter to reach from the top of the capitals on the first
+    /// line to the baseline of the last line it spans.
+    pub font: Font,
+    /// Number of lines it spans.
+    pub lines: usize,
+    /// Width taken from the start of those lines, in 1/64 of a pixel.
+    pub indent: usize,
+    /// Rows of the letter's bitmap above the top of the capitals of the first line.
+    pub overhang: usize,
+}
+
+impl DropCap {
+    /// The drop cap spanning `lines` lines for text starting with `letter`, if any.
+    fn new(letter: char, font: &Font, lines: usize) -> Option<Self> {
+        let cap = drop_cap_font(font, lines)?;
+        let (top, _) = cap_rows(font)?;
+        let (cap_top, _) = cap_rows(&cap)?;
+        Some(Self {
+            letter,
+            font: cap,
+            lines,
+            indent: cap.advance(letter) + font.advance(' '),
+            overhang: cap_top.saturating_sub(top),
+        })
+    }
+}
+
+/// The font a drop cap spanning `lines` lines of text in `font` is drawn with. Drop
+/// caps span at least two lines.
+pub(crate) fn drop_cap_font(font: &Font, lines: usize) -> Option<Font> {
+    if lines < 2 {
+        return None;
+    }
+    let (top, baseline) = cap_rows(font)?;
+    let cap_height = (baseline - top) as f32;
+    let height = (lines - 1) as f32 * font.line_height() as f32 + cap_height;
+    Some(font.scaled(font.height * font.scale * height / cap_height))
+}
+
+/// Top and bottom of the capital letters of the font once scaled, in pixels from the top
+/// of the line, going by the ink of `H`.
+fn cap_rows(font: &Font) -> Option<(usize, usize)> {
+    font.ink_rows('H')
+}
+
+/// The lines of a title, broken to fit the text.
+pub(crate) fn title_lines(title: &Title, max_width: usize) -> Vec<Line> {
+    layout(
+        &filter_text(title.text, &title.font),
+        max_width,
+        &title.font,
+    )
+}
+
+/// A single line of text after line breaking.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Line {
+    pub text: String,
+    /// Exact width of the text, or height of a column of vertical text, in 1/64 of a
+    /// pixel.
+    pub advance: usize,
+    /// Index of the paragraph (newline-separated block of text) this line belongs to.
+    pub paragraph: usize,
+    /// Whether the paragraph runs right to left. The text is kept in logical order.
+    pub rtl: bool,
+    /// Room left at the start of the line for a drop cap, in 1/64 of a pixel.
+    pub indent: usize,
+    /// The illustration drawn in place of text, if the line is one.
+    pub figure: Option<Figure>,
+    /// Offset of the line in the text it was broken from, in bytes.
+    pub start: usize,
+    /// For each byte of the line and one past its end, the offset in the text passed in
+    /// it came from. Only kept when there are [`ImageOptions::spans`] or
+    /// [`ImageOptions::highlights`].
+    pub origins: Vec<usize>,
+}
+
+/// An illustration placed between paragraphs, scaled to fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Figure {
+    /// Index in [`ImageOptions::illustrations`].
+    pub index: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Line {
+    /// A line of text.
+    pub fn new(text: &str, advance: usize, paragraph: usize, rtl: bool) -> Self {
+        Self {
+            text: String::from(text),
+            advance,
+            paragraph,
+            rtl,
+            indent: 0,
+            figure: None,
+            start: 0,
+            origins: Vec::new(),
+        }
+    }
+
+    /// Width of the text, or height of a column, in whole pixels, rounded up.
+    pub fn width(&self) -> usize {
+        self.advance.div_ceil(SUBPIXELS)
+    }
+
+    /// Height of the line, which is `line_height` unless it holds an illustration.
+    pub fn height(&self, line_height: usize) -> usize {
+        self.figure.map_or(line_height, |figure| figure.height)
+    }
+
+    /// Whether any character of the line came from the given range of the text passed
+    /// in.
+    pub fn covers(&self, range: &Range<usize>) -> bool {
+        let origins = &self.origins[..self.origins.len().saturating_sub(1)];
+        origins.iter().any(|origin| range.contains(origin))
+    }
+
+    /// Whether the line goes on from the same paragraph as `previous`. Illustrations
+    /// stand on their own.
+    fn continues(&self, previous: &Line) -> bool {
+        self.paragraph == previous.paragraph && self.figure.is_none() && previous.figure.is_none()
+    }
+}
+
+/// Width of a run of text in 1/64 of a pixel.
+#[cfg(test)]
+pub(crate) fn text_advance(text: &str, font: &Font) -> usize {
+    text.chars().map(|c| font.advance(c)).sum()
+}
+
+/// Break the text into the lines of a page, or the columns of vertical text, along with
+/// the metrics they are stacked with.
+pub(crate) fn flow(text: &str, font: &Font, options: &ImageOptions) -> (Vec<Line>, Metrics) {
+    let mut metrics = Metrics::new(font, options);
+    let track = !options.spans.is_empty() || options.highlights.is_some();
+    let prepared = crate::prepare(text, font, track);
+    let text = match options.typography {
+        Some(language) => typography::smarten_mapped(&prepared.text, language, font, track)
+            .through(prepared.origins.as_deref()),
+        None => prepared,
+    };
+    match options.writing_mode {
+        WritingMode::Horizontal => {
+            let (rest, drop_cap) = drop_cap(&text.text, font, options.drop_cap);
+            let indent = drop_cap.map_or((0, 0), |cap| (cap.lines, cap.indent));
+            let mut lines = break_lines(
+                rest,
+                metrics.column_width() * SUBPIXELS,
+                |c| font.advance(c),
+                false,
+                indent,
+            );
+            let skipped = text.text.len() - rest.len();
+            keep_origins(&mut lines, text.origins.as_deref(), skipped);
+            metrics.drop_cap = drop_cap;
+            (
+                place_figures(lines, options.illustrations, &metrics),
+                metrics,
+            )
+        }
+        WritingMode::VerticalRl => {
+            let metrics = metrics.across();
+            let mut lines = break_lines(
+                &text.text,
+                metrics.text_width() * SUBPIXELS,
+                |c| vertical::advance(font, c),
+                true,
+                (0, 0),
+            );
+            keep_origins(&mut lines, text.origins.as_deref(), 0);
+            (lines, metrics)
+        }
+    }
+}
+
+/// Give each line the origins of its bytes, if they are tracked, for lines broken from
+/// the text after its first `skipped` bytes.
+fn keep_origins(lines: &mut [Line], origins: Option<&[usize]>, skipped: usize) {
+    let Some(origins) = origins else {
+        return;
+    };
+    for line in lines {
+        let start = skipped + line.start;
+        line.origins = origins[start..=start + line.text.len()].to_vec();
+    }
+}
+
+/// Break the text into lines no wider than `max_width`, keeping track of which
+/// paragraph each line came from.
+pub(crate) fn layout(text: &str, max_width: usize, font: &Font) -> Vec<Line> {
+    break_lines(
+        text,
+        max_width * SUBPIXELS,
+        |c| font.advance(c),
+        false,
+        (0, 0),
+    )
+}
+
+/// Take the first letter off the text for a drop cap spanning `lines` lines, if the
+/// text starts with a letter or digit in a left to right paragraph.
+pub(crate) fn drop_cap<'t>(text: &'t str, font: &Font, lines: usize) -> (&'t str, Option<DropCap>) {
+    let Some(letter) = text.chars().next() else {
+        return (text, None);
+    };
+    let first = text.lines().next().unwrap_or("");
+    if !letter.is_alphanumeric() || bidi::is_rtl(first) {
+        return (text, None);
+    }
+    match DropCap::new(letter, font, lines) {
+        Some(cap) => (text[letter.len_utf8()..].trim_start_matches(' '), Some(cap)),
+        None => (text, None),
+    }
+}
+
+/// Break the text into lines no longer than `max_advance` at the break opportunities
+/// of [`linebreak::opportunities`], measuring each character with `advance`. Words too
+/// long for a line of their own are split between characters if `split_words` is set,
+/// and overflow the line otherwise. The first `indent.0` lines are shortened by
+/// `indent.1`.
+pub(crate) fn break_lines(
+    text: &str,
+    max_advance: usize,
+    advance: impl Fn(char) -> usize,
+    split_words: bool,
+    (indented, indent): (usize, usize),
+) -> Vec<Line> {
+    let measure = |text: &str| text.chars().map(&advance).sum::<usize>();
+    let indent = |line: usize| match line < indented {
+        true => indent,
+        false => 0,
+    };
+    let limit = |line: usize| max_advance.saturating_sub(indent(line));
+    let mut lines = Vec::new();
+
+    // Paragraphs are slices of the text, so their offset is how far apart they are.
+    let base = text.as_ptr() as usize;
+    for (paragraph, text) in text.lines().enumerate() {
+        let offset = text.as_ptr() as usize - base;
+        let rtl = bidi::is_rtl(text);
+        let text_advance = measure(text);
+        let mut count = lines.len();
+        if text_advance <= limit(count) {
+            lines.push(Line {
+                indent: indent(count),
+                start: offset,
+                ..Line::new(text, text_advance, paragraph, rtl)
+            });
+            continue;
+        }
+
+        let mut push = |range: Range<usize>, advance, indent| {
+            lines.push(Line {
+                indent,
+                start: offset + range.start,
+                ..Line::new(&text[range], advance, paragraph, rtl)
+            })
+        };
+        let mut max_advance = limit(count);
+        // The line so far is `text[start..end]`, followed by spaces that are only kept
+        // if more text fits after them.
+        let (mut start, mut end) = (0, 0);
+        let (mut line_advance, mut spaces) = (0, 0);
+        let mut from = 0;
+        for to in linebreak::opportunities(text)
+            .into_iter()
+            .chain([text.len()])
+        {
+            let segment = &text[from..to];
+            let word = segment.trim_end_matches(' ');
+            let word_advance = measure(word);
+            if end > start && line_advance + spaces + word_advance > max_advance {
+                push(start..end, line_advance, indent(count));
+                count += 1;
+                max_advance = limit(count);
+                (start, end) = (from, from);
+                (line_advance, spaces) = (0, 0);
+            }
+
+            if end == start && split_words && word_advance > max_advance {
+                // Columns of vertical text are narrow enough for a word not to fit,
+                // so it is broken wherever the column fills up.
+                for (i, c) in word.char_indices() {
+                    if end > start && line_advance + advance(c) > max_advance {
+                        push(start..end, line_advance, indent(count));
+                        count += 1;
+                        max_advance = limit(count);
+                        (start, line_advance) = (from + i, 0);
+                    }
+                    line_advance += advance(c);
+                    end = from + i + c.len_utf8();
+                }
+            } else {
+                line_advance += spaces + word_advance;
+                end = from + word.len();
+            }
+            spaces = measure(&segment[word.len()..]);
+            from = to;
+        }
+        push(start..end, line_advance, indent(count));
+    }
+
+    if lines.is_empty() {
+        lines.push(Line::new("", 0, 0, false));
+    }
+    lines
+}
+
+/// Put each illustration before the first line of its paragraph, scaled to the width of
+/// a column, or down to the height of one if it is too tall to fit.
+fn place_figures(
+    mut lines: Vec<Line>,
+    illustrations: &[Illustration],
+    metrics: &Metrics,
+) -> Vec<Line> {
+    let max_width = metrics.column_width();
+    // The text block of a page, which the title shortens on the first one only. Pages
+    // counted in lines have as many lines of text.
+    let max_height = |first: bool| match metrics.page_height {
+        Some(height) => {
+            let title = if first { metrics.title_height } else { 0 };
+            height
+                .saturating_sub(metrics.padding_height() + title)
+                .max(1)
+        }
+        None => (metrics.lines * metrics.line_height).max(1),
+    };
+    let scale =
+        |size: usize, from: usize, to: usize| round(size as f32 * to as f32 / from as f32).max(1);
+    // Inserted from the last, so that earlier illustrations end up before later ones
+    // in the same place.
+    for (index, illustration) in illustrations.iter().enumerate().rev() {
+        let paragraph = illustration.paragraph;
+        let at = lines
+            .iter()
+            .position(|l| l.paragraph >= paragraph)
+            .unwrap_or(lines.len());
+        // Only an illustration ahead of all the text and the other illustrations starts
+        // the first page, anything after it moves on to the next page if it is too tall.
+        // With several columns, it may start any of the columns below the title.
+        let ahead = at == 0
+            && illustrations[..index]
+                .iter()
+                .all(|i| i.paragraph > paragraph);
+        let first = ahead || metrics.columns > 1;
+        let max_height = max_height(first);
+        let mut width = max_width;
+        let mut height = scale(illustration.height, illustration.width, max_width);
+        if height > max_height {
+            width = scale(illustration.width, illustration.height, max_height);
+            height = max_height;
+        }
+        let figure = Figure {
+            index,
+            width,
+            height,
+        };
+        lines.insert(
+            at,
+            Line {
+                figure: Some(figure),
+                ..Line::new("", width * SUBPIXELS, paragraph, false)
+            },
+        );
+    }
+    lines
+}
+
+/// Vertical position of each line relative to the top of the text area, taking
+/// paragraph spacing into account.
+pub(crate) fn line_offsets(lines: &[Line], metrics: &Metrics) -> Vec<usize> {
+    let mut y = 0;
+    let mut offsets = Vec::with_capacity(lines.len());
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            y += lines[i - 1].height(metrics.line_height);
+            if !line.continues(&lines[i - 1]) {
+                y += metrics.paragraph_spacing;
+            }
+        }
+        offsets.push(y);
+    }
+    offsets
+}
+
+/// Height of the given lines, without padding.
+pub(crate) fn text_height(lines: &[Line], metrics: &Metrics) -> usize {
+    let last = lines.last().map_or(0, |l| l.height(metrics.line_height));
+    line_offsets(lines, metrics).last().map_or(0, |y| y + last)
+}
+
+/// Split the lines into pages, returning the range of lines on each page.
+pub(crate) fn paginate(lines: &[Line], metrics: &Metrics) -> Vec<Range<usize>> {
+    columns(lines, metrics)
+        .chunks(metrics.columns)
+        .map(|columns| columns[0].start..columns[columns.len() - 1].end)
+        .collect()
+}
+
+/// Split the lines into the columns of consecutive pages, returning the range of lines
+/// in each column. Text fills a column before flowing into the next one.
+pub(crate) fn columns(lines: &[Line], metrics: &Metrics) -> Vec<Range<usize>> {
+    let Some(page_height) = metrics.page_height else {
+        return (0..lines.len())
+            .step_by(metrics.lines)
+            .map(|start| start..core::cmp::min(lines.len(), start + metrics.lines))
+            .collect();
+    };
+
+    let available = page_height.saturating_sub(metrics.padding_height());
+    let mut columns = Vec::new();
+    let mut start = 0;
+    while start < lines.len() {
+        let mut end = start;
+        // The title takes up the top of the first page.
+        let mut used = match columns.len() < metrics.columns {
+            true => metrics.title_height,
+            false => 0,
+        };
+        while end < lines.len() {
+            let mut needed = lines[end].height(metrics.line_height);
+            if end > start && !lines[end].continues(&lines[end - 1]) {
+                needed += metrics.paragraph_spacing;
+            }
+            // A column always holds at least one line, even if it overflows.
+            if end > start && used + needed > available {
+                break;
+            }
+            used += needed;
+            end += 1;
+        }
+
+        if end < lines.len() {
+            end = keep_together(lines, start, end, metrics);
+        }
+        columns.push(start..end);
+        start = end;
+    }
+    columns
+}
+
+/// Split the lines into `count` columns of about the same number of lines, for showing
+/// all the text at once.
+pub(crate) fn balance(lines: Range<usize>, count: usize) -> Vec<Range<usize>> {
+    let per_column = lines.len().div_ceil(count).max(1);
+    (lines.start..lines.end)
+        .step_by(per_column)
+        .map(|start| start..(start + per_column).min(lines.end))
+        .collect()
+}
+
+/// Move a column or page break that would otherwise leave fewer than `metrics.orphans`
+/// lines of a paragraph at the bottom of the column, or fewer than `metrics.widows` lines
+/// at the top of the next one.
+fn keep_together(lines: &[Line], start: usize, end: usize, metrics: &Metrics) -> usize {
+    let line = &lines[end];
+    if !line.continues(&lines[end - 1]) {
+        return end;
+    }
+
+    let first = lines[..end]
+        .iter()
+        .rposition(|l| !line.continues(l))
+        .map_or(0, |i| i + 1)
+        .max(start);
+    let last = lines[end..]
+        .iter()
+        .position(|l| !l.continues(line))
+        .map_or(lines.len(), |i| end + i);
+
+    let mut brk = end;
+    let after = last - end;
+    if after < metrics.widows {
+        brk = brk.saturating_sub(metrics.widows - after).max(first);
+    }
+    if brk - first < metrics.orphans {
+        brk = first;
+    }
+
+    // The paragraph takes up the whole column, there is nothing to move.
+    if brk == start {
+        end
+    } else {
+        brk
+    }
+}
@@ -18,16 +18,16 @@
 //! ```
 //!
 //! ## Caveats
-//! * Does not support Unicode characters. Any characters between ASCII 32 and 126 will
-//! be converted, and the rest will be spaces.
+//! * The built-in fonts only cover ASCII 32 to 126. Other characters are dropped, or
+//!   transliterated to ASCII with the `transliterate` feature.
 //! * Fonts need to be bundled with the final binary, and it can accumulate a lot of
-//! binary data.
+//!   binary data.
 //! * Fonts follow a specific format. To generate a font, check the FontToJson.java file
-//! in the `fonts` module.
+//!   in the `fonts` module.
 //!
 //! ## Credits
 //! * [JimIsWayTooEpic](https://github.com/phiefferj24) for creating the original TypeScript
-//! library which this is based on.
+//!   library which this is based on.
 #![no_std]
 #![feature(test)]
 #![allow(clippy::needless_range_loop)]
@@ -41,8 +41,44 @@ mod bench;
 pub mod fonts;
 use fonts::Font;
 
+mod error;
+pub use error::Error;
+
+mod bidi;
+mod decoration;
+mod heads;
+mod layout;
+mod linebreak;
+mod mapped;
+use mapped::Mapped;
+mod page;
+use page::Page;
+mod raster;
+mod resample;
+#[cfg(feature = "transliterate")]
+mod transliterate;
+#[cfg(feature = "transliterate")]
+use transliterate::{replacement, transliterate};
+mod vertical;
+
+mod stream;
+use stream::SliceWriter;
+pub use stream::Write;
+
+/// BMP output options.
+pub mod bmp;
+#[cfg_attr(not(test), cfg(feature = "pnm"))]
+mod pnm;
+#[cfg_attr(not(test), cfg(feature = "qoi"))]
+mod qoi;
+/// Typographic clean-up of plain text.
+pub mod typography;
+#[cfg_attr(not(test), cfg(feature = "webp"))]
+mod webp;
+
 extern crate alloc;
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
 
 /// Struct representing text padding, used to tell the library to add margins
 /// to the text.
@@ -54,12 +90,311 @@ pub struct Padding(
     pub f32,
 );
 
+/// Margins on each side of the page, for pages that are not symmetric such as the
+/// pages of a bound book or screens with a notch.
+///
+/// Each side is in pixels, rounded up to a whole one.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Margins {
+    /// Space above the text, and the title if there is one. A running header is drawn
+    /// in it, and it grows to twice the header height if it is less.
+    pub top: f32,
+    /// Space right of the text.
+    pub right: f32,
+    /// Space below the text. A running footer is drawn in it, and it grows to twice the
+    /// footer height if it is less.
+    pub bottom: f32,
+    /// Space left of the text.
+    pub left: f32,
+}
+
+impl Margins {
+    /// Margins given in the order `top`, `right`, `bottom`, `left`.
+    pub const fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+impl From<Padding> for Margins {
+    /// The horizontal padding on the left and right, the vertical one at the top and
+    /// bottom.
+    fn from(Padding(x, y): Padding) -> Self {
+        Self::new(y, x, y, x)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BitmapPixel(pub u8, pub u8, pub u8);
 
+/// Directions text can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum WritingMode {
+    /// Lines running left to right, stacked from the top of the page.
+    #[default]
+    Horizontal,
+
+    /// Columns running top to bottom, stacked from the right of the page, as Japanese
+    /// and Chinese novels are typeset. CJK characters stand upright, while other text
+    /// and the brackets and dashes that follow the direction of the text are turned
+    /// sideways.
+    ///
+    /// Pages are filled with as many columns as fit in the page width, so `page_height`
+    /// must be set. Page 0 is made as wide as needed to hold every column.
+    VerticalRl,
+}
+
+/// A running header or footer, drawn centered in the top or bottom padding of every
+/// page. The padding grows to twice the height of the header or footer if it is smaller,
+/// leaving less room for text.
+///
+/// Running heads are not drawn on page 0, which holds all text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunningHead<'a> {
+    /// The font of the text, which may be smaller than the one of the page.
+    pub font: Font,
+
+    /// Text aligned to the left, center and right of the text area, such as the chapter
+    /// title or `"Page {page} / {pages}"`. `{page}` and `{pages}` are replaced with
+    /// the page number and the number of pages, and `{percent}` with how far through
+    /// the text the page is.
+    pub left: &'a str,
+    pub center: &'a str,
+    pub right: &'a str,
+
+    /// Draw a bar below the text across the text area, filled as far as the page is
+    /// through the text.
+    pub progress_bar: bool,
+}
+
+impl<'a> RunningHead<'a> {
+    /// An empty running head using the given font.
+    pub fn new(font: Font) -> Self {
+        Self {
+            font,
+            left: "",
+            center: "",
+            right: "",
+            progress_bar: false,
+        }
+    }
+
+    /// Whether there is any text to draw.
+    fn has_text(&self) -> bool {
+        !(self.left.is_empty() && self.center.is_empty() && self.right.is_empty())
+    }
+
+    /// Thickness of the progress bar in pixels.
+    pub(crate) fn bar_height(&self) -> usize {
+        (self.font.line_height() / 6).max(2)
+    }
+
+    /// Height of the text and progress bar in pixels.
+    pub(crate) fn height(&self) -> usize {
+        let text = match self.has_text() {
+            true => self.font.line_height(),
+            false => 0,
+        };
+        match self.progress_bar {
+            true => text + self.bar_height() * 2,
+            false => text,
+        }
+    }
+}
+
+/// A chapter title, drawn centered at the top of the first page. Lines of the title
+/// are broken like the text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Title<'a> {
+    /// The title, which may span several lines.
+    pub text: &'a str,
+
+    /// The font of the title, usually larger than the one of the page.
+    pub font: Font,
+
+    /// Space between the title and the text.
+    pub spacing: f32,
+}
+
+/// An image drawn behind the text, such as a paper texture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Background<'a> {
+    /// 24-bit BGR pixels, row by row from the top.
+    pub pixels: &'a [u8],
+    pub width: usize,
+    pub height: usize,
+    /// How the image covers the page.
+    pub fit: Fit,
+}
+
+/// Ways a [`Background`] image covers the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Fit {
+    /// Repeated from the top left corner.
+    #[default]
+    Tile,
+
+    /// Stretched over the whole page, with bilinear filtering.
+    Stretch,
+}
+
+/// A picture set between paragraphs, scaled to the width of the text, or of a column,
+/// and kept whole on a page. Pictures too tall for a page are scaled down to fit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Illustration<'a> {
+    /// 24-bit BGR pixels, row by row from the top.
+    pub pixels: &'a [u8],
+    pub width: usize,
+    pub height: usize,
+    /// Index of the paragraph (newline-separated block of text) the illustration goes
+    /// before. Past the last paragraph, it goes after the text.
+    pub paragraph: usize,
+}
+
+/// A rectangle drawn around the text, as a border or as a card with rounded corners.
+/// Its edges are antialiased.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame {
+    /// Distance from the edges of the page. The padding should leave room for the frame
+    /// inside of it.
+    pub inset: f32,
+
+    /// Thickness of the outline, 0 for none.
+    pub thickness: f32,
+
+    /// Radius of the corners.
+    pub radius: f32,
+
+    /// Color of the outline, in the same format as the text color.
+    pub color: usize,
+
+    /// Color the frame is filled with, if any.
+    pub fill: Option<usize>,
+}
+
+impl Frame {
+    /// A border of the given thickness along the edges of the page.
+    pub fn border(thickness: f32, color: usize) -> Self {
+        Self {
+            inset: 0.0,
+            thickness,
+            radius: 0.0,
+            color,
+            fill: None,
+        }
+    }
+
+    /// A card with rounded corners filled with the given color, set in from the edges
+    /// of the page.
+    pub fn card(inset: f32, radius: f32, fill: usize) -> Self {
+        Self {
+            inset,
+            thickness: 0.0,
+            radius,
+            color: fill,
+            fill: Some(fill),
+        }
+    }
+}
+
+/// A decoration drawn along part of the text, such as a translator's note or a
+/// struck-out word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offsets of the decorated part in the text passed in, as it is before being
+    /// transliterated or made typographic.
+    pub range: Range<usize>,
+    pub decoration: Decoration,
+}
+
+/// Ways a [`Span`] of text is decorated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Decoration {
+    /// A line under the text, as far below the baseline and as thick as the font's
+    /// hyphen.
+    Underline,
+
+    /// A line through the text, over the font's hyphen.
+    Strikethrough,
+
+    /// A rectangle of the given color behind the text, as tall as the line.
+    Highlight(usize),
+}
+
+/// Parts of the text to highlight, such as search hits, given as ranges of bytes or of
+/// characters. See [`highlight_pages`] to find the page each lands on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Highlights<'a> {
+    pub ranges: &'a [Range<usize>],
+
+    /// Whether the ranges count bytes or characters of the text passed in.
+    pub unit: Unit,
+
+    /// Color drawn behind the highlighted text, in the same format as the text color.
+    pub color: usize,
+}
+
+/// What a range of text counts, see [`Highlights`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Unit {
+    /// Byte offsets, as given by [`str::find`].
+    #[default]
+    Bytes,
+
+    /// Indices of characters, as given by [`str::chars`].
+    Chars,
+}
+
+impl<'a> Highlights<'a> {
+    /// Highlight the given byte ranges of the text.
+    pub fn bytes(ranges: &'a [Range<usize>], color: usize) -> Self {
+        Self {
+            ranges,
+            unit: Unit::Bytes,
+            color,
+        }
+    }
+
+    /// Highlight the given ranges of characters of the text.
+    pub fn chars(ranges: &'a [Range<usize>], color: usize) -> Self {
+        Self {
+            ranges,
+            unit: Unit::Chars,
+            color,
+        }
+    }
+
+    /// The highlights as spans over the bytes of the text.
+    pub(crate) fn spans(&self, text: &str) -> Vec<Span> {
+        let starts: Vec<usize> = match self.unit {
+            Unit::Bytes => Vec::new(),
+            Unit::Chars => text.char_indices().map(|(i, _)| i).collect(),
+        };
+        let byte = |i: usize| match self.unit {
+            Unit::Bytes => i,
+            Unit::Chars => starts.get(i).copied().unwrap_or(text.len()),
+        };
+        self.ranges
+            .iter()
+            .map(|range| Span {
+                range: byte(range.start)..byte(range.end),
+                decoration: Decoration::Highlight(self.color),
+            })
+            .collect()
+    }
+}
+
 /// Rendering options.
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ImageOptions {
+pub struct ImageOptions<'a> {
     /// Text color. It is an usize, but can be written in an BGR-like format
     /// using hex notation, e.g., `0x1F1E33`.
     pub text_color: usize,
@@ -68,8 +403,8 @@ pub struct ImageOptions {
     /// color.
     pub background_color: usize,
 
-    /// The margins for the generated page.
-    pub padding: Padding,
+    /// The margins for the generated page. A [`Padding`] can be converted into them.
+    pub padding: Margins,
 
     /// Maximum page width.
     pub width: f32,
@@ -77,23 +412,312 @@ pub struct ImageOptions {
     /// Whether the renderer should force the given max width or not.
     pub constant_width: bool,
 
-    /// The number of lines in a given page.
+    /// The number of lines in a given page, or in each of its columns. Ignored if
+    /// `page_height` is set.
     pub lines: usize,
+
+    /// Maximum page height, including the vertical padding. When set, pages are
+    /// filled with as many lines as fit instead of a fixed number of lines.
+    pub page_height: Option<f32>,
+
+    /// Extra vertical space added between paragraphs.
+    pub paragraph_spacing: f32,
+
+    /// Minimum number of lines of a paragraph that must be left at the bottom of
+    /// a page. Only used when paginating by `page_height`.
+    pub orphans: usize,
+
+    /// Minimum number of lines of a paragraph that must be carried over to the top
+    /// of the next page. Only used when paginating by `page_height`.
+    pub widows: usize,
+
+    /// The image format to output.
+    pub format: ImageFormat,
+
+    /// The direction the text is written in.
+    pub writing_mode: WritingMode,
+
+    /// Convert straight quotes, `--` and `...` to their typographic forms following the
+    /// conventions of the given language before laying out the text, see
+    /// [`typography::smarten`].
+    pub typography: Option<typography::Language>,
+
+    /// Running header, drawn in the top padding.
+    pub header: Option<RunningHead<'a>>,
+
+    /// Running footer, drawn in the bottom padding.
+    pub footer: Option<RunningHead<'a>>,
+
+    /// Title drawn above the text on the first page. Only used for horizontal text.
+    pub title: Option<Title<'a>>,
+
+    /// Number of lines the first letter of the text spans as a drop cap, with the
+    /// lines beside it narrowed to make room, or 0 for none. The text must start with
+    /// a letter or digit in a left to right paragraph. Only used for horizontal text.
+    pub drop_cap: usize,
+
+    /// Number of columns the text is set in, side by side across `width`. Text fills a
+    /// column before flowing into the next, and the next page once all are full. Only
+    /// used for horizontal text.
+    pub columns: usize,
+
+    /// Space between columns.
+    pub gutter: f32,
+
+    /// Image drawn over the background color, behind everything else.
+    pub background: Option<Background<'a>>,
+
+    /// Border or card drawn behind the text.
+    pub frame: Option<Frame>,
+
+    /// Illustrations set between paragraphs. Only used for horizontal text.
+    pub illustrations: &'a [Illustration<'a>],
+
+    /// A line of text, such as `* * *`, that stands for a break between scenes. Lines
+    /// holding only it are drawn as a horizontal rule. Only used for horizontal text.
+    pub scene_break: Option<&'a str>,
+
+    /// Decorations drawn along parts of the text, in the text color unless they say
+    /// otherwise. Spans may overlap. Only used for horizontal text, and not for the
+    /// letter taken out for a drop cap.
+    pub spans: &'a [Span],
+
+    /// Parts of the text to highlight, drawn behind it after the spans. They are only
+    /// drawn for horizontal text, but [`highlight_pages`] finds them in vertical text too.
+    pub highlights: Option<Highlights<'a>>,
 }
 
-impl Default for ImageOptions {
+/// Output image formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// Windows bitmap.
+    Bmp(bmp::BmpOptions),
+
+    /// [Quite OK Image](https://qoiformat.org) format, losslessly compressed.
+    /// Requires the `qoi` feature.
+    #[cfg_attr(not(test), cfg(feature = "qoi"))]
+    Qoi,
+
+    /// Binary Netpbm pixmap (PPM) with 24-bit color. Requires the `pnm` feature.
+    #[cfg_attr(not(test), cfg(feature = "pnm"))]
+    Ppm,
+
+    /// Binary Netpbm graymap (PGM) with 8-bit grayscale. Requires the `pnm` feature.
+    #[cfg_attr(not(test), cfg(feature = "pnm"))]
+    Pgm,
+
+    /// Lossless WebP, the most compact format for pages of text. Requires the `webp`
+    /// feature.
+    ///
+    /// The whole image is held in memory, 4 bytes per pixel, while it is compressed,
+    /// even when written with [`write_text_to`].
+    #[cfg_attr(not(test), cfg(feature = "webp"))]
+    WebP,
+}
+
+impl Default for ImageFormat {
+    fn default() -> Self {
+        Self::Bmp(bmp::BmpOptions::default())
+    }
+}
+
+impl ImageFormat {
+    /// Size of the encoded image, or an upper bound of it for compressed formats.
+    fn encoded_size(&self, page: &Page) -> Result<usize, Error> {
+        match self {
+            ImageFormat::Bmp(options) => bmp::encoded_size(page, options),
+            #[cfg_attr(not(test), cfg(feature = "qoi"))]
+            ImageFormat::Qoi => qoi::encoded_size(page.width, page.height),
+            #[cfg_attr(not(test), cfg(feature = "pnm"))]
+            ImageFormat::Ppm => pnm::encoded_size(pnm::Kind::Pixmap, page.width, page.height),
+            #[cfg_attr(not(test), cfg(feature = "pnm"))]
+            ImageFormat::Pgm => pnm::encoded_size(pnm::Kind::Graymap, page.width, page.height),
+            #[cfg_attr(not(test), cfg(feature = "webp"))]
+            ImageFormat::WebP => webp::encoded_size(page.width, page.height),
+        }
+    }
+
+    /// The BMP options, if the image is a BMP.
+    fn bmp_options(&self) -> Option<&bmp::BmpOptions> {
+        match self {
+            ImageFormat::Bmp(options) => Some(options),
+            #[cfg_attr(
+                not(test),
+                cfg(any(feature = "qoi", feature = "pnm", feature = "webp"))
+            )]
+            _ => None,
+        }
+    }
+
+    fn encode<W: Write>(&self, page: &Page, writer: &mut W) -> Result<(), Error> {
+        match self {
+            ImageFormat::Bmp(options) => bmp::encode(page, options, writer),
+            #[cfg_attr(not(test), cfg(feature = "qoi"))]
+            ImageFormat::Qoi => qoi::encode(page, writer),
+            #[cfg_attr(not(test), cfg(feature = "pnm"))]
+            ImageFormat::Ppm => pnm::encode(pnm::Kind::Pixmap, page, writer),
+            #[cfg_attr(not(test), cfg(feature = "pnm"))]
+            ImageFormat::Pgm => pnm::encode(pnm::Kind::Graymap, page, writer),
+            #[cfg_attr(not(test), cfg(feature = "webp"))]
+            ImageFormat::WebP => webp::encode(page, writer),
+        }
+    }
+}
+
+impl Default for ImageOptions<'_> {
     fn default() -> Self {
         Self {
             text_color: 0,
             background_color: 0xFFFFFF,
-            padding: Padding(20.0, 20.0),
+            padding: Margins::from(Padding(20.0, 20.0)),
             width: 800.0,
             constant_width: true,
             lines: 60,
+            page_height: None,
+            paragraph_spacing: 0.0,
+            orphans: 2,
+            widows: 2,
+            format: ImageFormat::default(),
+            writing_mode: WritingMode::default(),
+            typography: None,
+            header: None,
+            footer: None,
+            title: None,
+            drop_cap: 0,
+            columns: 1,
+            gutter: 20.0,
+            background: None,
+            frame: None,
+            illustrations: &[],
+            scene_break: None,
+            spans: &[],
+            highlights: None,
         }
     }
 }
 
+impl ImageOptions<'_> {
+    /// Check that the options can be used to render text with the given font.
+    pub fn validate(&self, font: &Font) -> Result<(), Error> {
+        let heads = [self.header, self.footer];
+        let head_fonts = heads.iter().flatten().map(|head| &head.font);
+        let title_font = self.title.as_ref().map(|title| &title.font);
+        let fonts = [Some(font), title_font];
+        for font in fonts.into_iter().flatten().chain(head_fonts) {
+            if !font.is_drawable() {
+                return Err(Error::InvalidFontSize(font.height * font.scale));
+            }
+        }
+        if let Some(cap) = layout::drop_cap_font(font, self.drop_cap) {
+            if !cap.is_drawable() {
+                return Err(Error::DropCapTooLarge(self.drop_cap));
+            }
+        }
+        if !(self.width.is_finite() && self.width > 0.0) {
+            return Err(Error::InvalidWidth(self.width));
+        }
+        let Margins {
+            top,
+            right,
+            bottom,
+            left,
+        } = self.padding;
+        if ![top, right, bottom, left]
+            .iter()
+            .all(|side| side.is_finite() && *side >= 0.0)
+        {
+            return Err(Error::InvalidPadding(self.padding));
+        }
+        if !(self.paragraph_spacing.is_finite() && self.paragraph_spacing >= 0.0) {
+            return Err(Error::InvalidParagraphSpacing(self.paragraph_spacing));
+        }
+        if let Some(title) = &self.title {
+            if !(title.spacing.is_finite() && title.spacing >= 0.0) {
+                return Err(Error::InvalidTitleSpacing(title.spacing));
+            }
+        }
+        if self.columns == 0 {
+            return Err(Error::ZeroColumns);
+        }
+        if let Some(background) = self.background {
+            let (pixels, width, height) = (background.pixels, background.width, background.height);
+            if !fits_pixels(pixels, width, height) {
+                return Err(Error::InvalidBackground {
+                    width,
+                    height,
+                    len: pixels.len(),
+                });
+            }
+        }
+        for (index, illustration) in self.illustrations.iter().enumerate() {
+            let (pixels, width, height) =
+                (illustration.pixels, illustration.width, illustration.height);
+            if !fits_pixels(pixels, width, height) {
+                return Err(Error::InvalidIllustration {
+                    index,
+                    width,
+                    height,
+                    len: pixels.len(),
+                });
+            }
+        }
+        if let Some(frame) = self.frame {
+            let sizes = [frame.inset, frame.thickness, frame.radius];
+            if !sizes.iter().all(|size| size.is_finite() && *size >= 0.0) {
+                return Err(Error::InvalidFrame(frame));
+            }
+        }
+
+        let metrics = layout::Metrics::new(font, self);
+        let min_width = match self.writing_mode {
+            WritingMode::Horizontal => 1,
+            WritingMode::VerticalRl => metrics.line_height,
+        };
+        if metrics.text_width() < min_width {
+            return Err(Error::PaddingTooLarge {
+                padding: left + right,
+                width: self.width,
+            });
+        }
+        let columns = self.writing_mode == WritingMode::Horizontal && self.columns > 1;
+        if columns && !(self.gutter.is_finite() && self.gutter >= 0.0 && metrics.column_width() > 0)
+        {
+            return Err(Error::InvalidGutter(self.gutter));
+        }
+        // The title sits above the first line of the first page.
+        let title_height = match self.writing_mode {
+            WritingMode::Horizontal => metrics.title_height,
+            WritingMode::VerticalRl => 0,
+        };
+        let required =
+            (metrics.line_height + metrics.padding_height()).saturating_add(title_height);
+        let text_height = self.lines.saturating_mul(metrics.line_height);
+        match (self.page_height, metrics.page_height) {
+            (Some(page_height), Some(height)) if page_height.is_nan() || height < required => {
+                Err(Error::PageTooShort {
+                    page_height,
+                    required: required as f32,
+                })
+            }
+            (None, _) if self.writing_mode == WritingMode::VerticalRl => Err(Error::NoPageHeight),
+            (None, _) if self.lines == 0 => Err(Error::ZeroLines),
+            // Pages counted in lines grow to fit the title, but not past the lines of text.
+            (None, _) if title_height > text_height => Err(Error::TitleTooTall {
+                height: title_height,
+                max: text_height,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Whether an image of the given size has pixels and all of them are there.
+fn fits_pixels(pixels: &[u8], width: usize, height: usize) -> bool {
+    let needed = width.checked_mul(height).and_then(|n| n.checked_mul(3));
+    width > 0 && height > 0 && needed.is_some_and(|n| pixels.len() >= n)
+}
+
 /// Struct representing split text.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Spliterated {
@@ -104,69 +728,162 @@ pub struct Spliterated {
     pub width: f32,
 }
 
-fn ceil(num: f32) -> f32 {
-    (num as i32 + 1) as f32
+/// Split the text into multiple lines based on a given maximum width and font.
+///
+/// Lines break where UAX #14 allows: after spaces, hyphens, dashes and slashes, and
+/// between CJK characters, but never before closing punctuation or small kana.
+///
+/// Lines are returned in logical order, right-to-left text is only reordered when drawn.
+pub fn break_apart<T: AsRef<str>>(text: T, max_width: f32, font: &Font) -> Spliterated {
+    break_apart_with_drop_cap(text, max_width, font, 0)
 }
 
-fn calculate_text_length<T: AsRef<str>>(text: T, font: &Font) -> f32 {
-    let mut ret = 0.0;
-    for c in text.as_ref().as_bytes() {
-        let curr = *c;
-        let idx = if (b' '..=127).contains(&curr) {
-            (curr - 32) as usize
-        } else {
-            0
-        };
-        ret += (font.font[idx].len() as f32) / font.height;
+/// Split the text like [`break_apart`], narrowing the first lines to make room for a drop
+/// cap spanning `drop_cap` lines, as set by [`ImageOptions::drop_cap`].
+///
+/// The letter of the drop cap is left out of the split text, and the width includes the
+/// room made for it.
+pub fn break_apart_with_drop_cap<T: AsRef<str>>(
+    text: T,
+    max_width: f32,
+    font: &Font,
+    drop_cap: usize,
+) -> Spliterated {
+    let (text, drop_cap) = layout::drop_cap(text.as_ref(), font, drop_cap);
+    let indent = drop_cap.map_or((0, 0), |cap| (cap.lines, cap.indent));
+    let lines = layout::break_lines(
+        text,
+        max_width.max(0.0) as usize * fonts::SUBPIXELS,
+        |c| font.advance(c),
+        false,
+        indent,
+    );
+    let advance = lines
+        .iter()
+        .map(|l| l.indent + l.advance)
+        .max()
+        .unwrap_or(0);
+    Spliterated {
+        split: lines.into_iter().map(|l| l.text).collect(),
+        width: advance as f32 / fonts::SUBPIXELS as f32,
     }
-    ret
 }
 
-/// Split the text into multiple lines based on a given maximum width and font.
-pub fn break_apart<T: AsRef<str>>(text: T, max_width: f32, font: &Font) -> Spliterated {
-    let width = calculate_text_length(&text, font);
-    if width <= max_width {
-        return Spliterated {
-            split: vec![String::from(text.as_ref())],
-            width,
-        };
-    }
+/// Get the number of pages the text will be split into with the given font and options.
+///
+/// # Panics
+/// Panics if the options are invalid, see [`ImageOptions::validate`].
+pub fn page_count<T: AsRef<str>>(text: T, font: &Font, options: &ImageOptions) -> usize {
+    unwrap(count_pages(text.as_ref(), font, options))
+}
 
-    let text = text.as_ref().replace('\n', "\n ");
-    let fullsplit = text
-        .split(' ')
-        .filter(|s| !s.is_empty())
-        .collect::<Vec<_>>();
-    let mut split = Vec::new();
-
-    let mut base = 0;
-    let mut maxlen = 0.0;
-    #[allow(unused_assignments)]
-    let mut prevlen = 0.0;
-    let mut curlen = 0.0;
-
-    for i in 0..fullsplit.len() {
-        prevlen = curlen;
-        curlen = calculate_text_length(fullsplit[base..i + 1].join(" "), font);
-        if curlen > max_width || (i >= 1 && fullsplit[i - 1].contains('\n')) {
-            split.push(fullsplit[base..i].join(" ").replace('\n', ""));
-            if prevlen > maxlen {
-                maxlen = prevlen;
-            }
-            base = i;
+/// Fallible version of [`page_count`], which also rejects empty text and characters
+/// the font cannot render instead of filtering them out.
+pub fn try_page_count<T: AsRef<str>>(
+    text: T,
+    font: &Font,
+    options: &ImageOptions,
+) -> Result<usize, Error> {
+    check_text(text.as_ref(), font)?;
+    count_pages(text.as_ref(), font, options)
+}
+
+fn count_pages(text: &str, font: &Font, options: &ImageOptions) -> Result<usize, Error> {
+    options.validate(font)?;
+    let (lines, metrics) = layout::flow(text, font, options);
+    Ok(layout::paginate(&lines, &metrics).len())
+}
+
+/// Get the page each of the [`ImageOptions::highlights`] lands on, counting from 1, or
+/// `None` for a range that covers no text that is drawn. A range spread over several
+/// pages is on the first of them.
+///
+/// # Panics
+/// Panics if the options are invalid, see [`ImageOptions::validate`].
+pub fn highlight_pages<T: AsRef<str>>(
+    text: T,
+    font: &Font,
+    options: &ImageOptions,
+) -> Vec<Option<usize>> {
+    unwrap(find_highlights(text.as_ref(), font, options))
+}
+
+/// Fallible version of [`highlight_pages`], which also rejects empty text and
+/// characters the font cannot render instead of filtering them out.
+pub fn try_highlight_pages<T: AsRef<str>>(
+    text: T,
+    font: &Font,
+    options: &ImageOptions,
+) -> Result<Vec<Option<usize>>, Error> {
+    check_text(text.as_ref(), font)?;
+    find_highlights(text.as_ref(), font, options)
+}
+
+fn find_highlights(
+    text: &str,
+    font: &Font,
+    options: &ImageOptions,
+) -> Result<Vec<Option<usize>>, Error> {
+    options.validate(font)?;
+    let Some(highlights) = &options.highlights else {
+        return Ok(Vec::new());
+    };
+    let (lines, metrics) = layout::flow(text, font, options);
+    let pages = layout::paginate(&lines, &metrics);
+    let page = |span: &Span| {
+        let line = lines.iter().position(|line| line.covers(&span.range))?;
+        Some(pages.iter().position(|page| page.contains(&line))? + 1)
+    };
+    Ok(highlights.spans(text).iter().map(page).collect())
+}
+
+fn filter_text(text: &str, font: &Font) -> String {
+    prepare(text, font, false).text
+}
+
+/// Transliterate the text and drop the characters the font still has no glyph for,
+/// tracking where each byte of the result came from if `track` is set.
+fn prepare(text: &str, font: &Font, track: bool) -> Mapped {
+    let mut out = Mapped::new(text.len(), track);
+    for (i, c) in text.char_indices() {
+        match replacement(c, font) {
+            Some(ascii) => out.push_str(ascii, i),
+            None if (c as u32) < 0x7F || font.has_glyph(c) => out.push(c, i),
+            None => {}
         }
     }
+    out.finish(text.len())
+}
+
+#[cfg(not(feature = "transliterate"))]
+fn transliterate<'a>(text: &'a str, _font: &Font) -> alloc::borrow::Cow<'a, str> {
+    alloc::borrow::Cow::Borrowed(text)
+}
 
-    split.push(fullsplit[base..].join(" "));
-    if curlen > maxlen {
-        maxlen = curlen;
+#[cfg(not(feature = "transliterate"))]
+fn replacement(_c: char, _font: &Font) -> Option<&'static str> {
+    None
+}
+
+/// Reject empty text and characters the font cannot render, once transliterated.
+fn check_text(text: &str, font: &Font) -> Result<(), Error> {
+    let text = transliterate(text, font);
+    if text.trim().is_empty() {
+        return Err(Error::EmptyText);
     }
-    Spliterated {
-        split,
-        width: maxlen,
+    match text
+        .chars()
+        .find(|&c| !(matches!(c, '\n' | '\r' | '\t') || font.has_glyph(c)))
+    {
+        Some(c) => Err(Error::UnsupportedCharacter(c)),
+        None => Ok(()),
     }
 }
 
+fn unwrap<T>(result: Result<T, Error>) -> T {
+    result.unwrap_or_else(|err| panic!("{err}"))
+}
+
 fn split_color(color: usize) -> BitmapPixel {
     BitmapPixel(
         (color & 0xFF) as u8,
@@ -178,128 +895,115 @@ fn split_color(color: usize) -> BitmapPixel {
 /// Turns text into a 3-dimensional array containing the color data for each pixel
 ///
 /// Set the page parameter to 0 to generate an image containing all text.
-/// 
-/// This assumes that the given text is ASCII. Anything not ASCII will be filtered out.
-/// You may want to preserve them by using a crate like [deunicode](https://lib.rs/crates/deunicode).
+///
+/// Characters the font has no glyph for are filtered out. With the `transliterate`
+/// feature, Latin letters with diacritics, common punctuation and Cyrillic and Greek
+/// letters are replaced with their ASCII spelling first, so "café — naïve" becomes
+/// "cafe - naive". The fallible functions transliterate the same way.
+///
+/// # Panics
+/// Panics if the options are invalid (see [`ImageOptions::validate`]) or the page
+/// does not exist. Use [`try_write_text`] to handle these cases.
 pub fn write_text<T: AsRef<str>>(
     text: T,
     page: usize,
     font: Font,
     options: ImageOptions,
 ) -> Vec<u8> {
-    let text = text.as_ref().chars().filter(|&c| (c as u8) < 0x7F).collect::<String>();
-
-    let spliterated = break_apart(text, options.width - options.padding.0 * 2.0, &font);
-    let split = if page >= 1 {
-        spliterated.split[(page - 1) * options.lines
-            ..core::cmp::min(spliterated.split.len(), page * options.lines)]
-            .to_vec()
-    } else {
-        spliterated.split
-    };
-
-    let width = if options.constant_width {
-        options.width
-    } else {
-        spliterated.width + 2.0 * options.padding.0
-    };
-    let height = (split.len() as f32) * font.height + options.padding.1 * 2.0;
-    let ceil_width = ceil(width) as usize;
-    let ceil_height = ceil(height) as usize;
-
-    let mut img = vec![split_color(options.background_color); ceil_width * ceil_height];
-    let mut line_at: usize = 0;
+    unwrap(render(text.as_ref(), page, &font, &options))
+}
 
-    for i in (options.padding.1 as usize)..((height - options.padding.1) as usize) {
-        if (i as f32) < options.padding.1 {
-            continue;
-        }
+/// Fallible version of [`write_text`].
+///
+/// Unlike [`write_text`], empty text and characters the font cannot render are
+/// reported as errors instead of being filtered out.
+pub fn try_write_text<T: AsRef<str>>(
+    text: T,
+    page: usize,
+    font: Font,
+    options: ImageOptions,
+) -> Result<Vec<u8>, Error> {
+    check_text(text.as_ref(), &font)?;
+    render(text.as_ref(), page, &font, &options)
+}
 
-        if (i as f32 - options.padding.1) % font.height == 0.0 {
-            line_at += 1;
-        }
+fn render(text: &str, page: usize, font: &Font, options: &ImageOptions) -> Result<Vec<u8>, Error> {
+    let page = Page::new(text, page, font, options)?;
+    let mut ret = Vec::with_capacity(options.format.encoded_size(&page)?);
+    options.format.encode(&page, &mut ret)?;
+    Ok(ret)
+}
 
-        let mut letter: &[u8] = &[];
-        let mut letter_on: usize = 0;
-        let mut letter_base = options.padding.0;
-        let bytes = split[line_at - 1].as_bytes();
-        for j in (ceil(options.padding.0) as usize)..((width - options.padding.0) as usize) {
-            if (j as f32) >= letter_base + (letter.len() as f32) / font.height {
-                letter_on += 1;
-                if letter_on > bytes.len() {
-                    continue;
-                }
-                letter_base = j as f32;
-                let mut char = bytes[letter_on - 1].saturating_sub(32);
-                if char >= 95 {
-                    char = 0;
-                }
-                letter = font.font[char as usize];
-            }
+/// Get the size in bytes of the buffer [`write_text_into`] needs. For compressed
+/// formats, this is an upper bound of the image size.
+pub fn required_size<T: AsRef<str>>(
+    text: T,
+    page: usize,
+    font: Font,
+    options: ImageOptions,
+) -> Result<usize, Error> {
+    check_text(text.as_ref(), &font)?;
+    let page = Page::new(text.as_ref(), page, &font, &options)?;
+    options.format.encoded_size(&page)
+}
 
-            let alpha = letter[(((i as f32 - options.padding.1)
-                - ((line_at - 1) as f32) * font.height)
-                * ((letter.len() as f32) / font.height)
-                + (j as f32 - letter_base)) as usize];
-
-            if alpha != 0 {
-                let colors = split_color(options.text_color);
-                img[i * ceil_width + j] = BitmapPixel(
-                    core::cmp::min(
-                        255,
-                        ((colors.0 as f32) * (alpha as f32) / 255.0
-                            + (colors.0 as f32) * (1.0 - alpha as f32 / 255.0))
-                            as u8,
-                    ),
-                    core::cmp::min(
-                        255,
-                        ((colors.1 as f32) * (alpha as f32) / 255.0
-                            + (colors.1 as f32) * (1.0 - alpha as f32 / 255.0))
-                            as u8,
-                    ),
-                    core::cmp::min(
-                        255,
-                        ((colors.2 as f32) * (alpha as f32) / 255.0
-                            + (colors.2 as f32) * (1.0 - alpha as f32 / 255.0))
-                            as u8,
-                    ),
-                );
-            }
-        }
-    }
+/// Get the pixel format a page is written in with [`ImageFormat::Bmp`], which is
+/// [`bmp::PixelFormat::Bgr24`] rather than the palette format asked for when the page
+/// has too many colors for it. Other image formats give `None`.
+pub fn bmp_pixel_format<T: AsRef<str>>(
+    text: T,
+    page: usize,
+    font: Font,
+    options: ImageOptions,
+) -> Result<Option<bmp::PixelFormat>, Error> {
+    let Some(bmp_options) = options.format.bmp_options() else {
+        return Ok(None);
+    };
+    check_text(text.as_ref(), &font)?;
+    let page = Page::new(text.as_ref(), page, &font, &options)?;
+    Ok(Some(bmp::pixel_format(&page, bmp_options)))
+}
 
-    let bytewidth = (((ceil_width as f32) * 3.0 / 4.0) + 0.5) as usize * 4;
-    let size = bytewidth * ceil_height;
-    let file_size = size + 54;
-
-    let mut ret = Vec::with_capacity(file_size);
-    ret.extend([0x42, 0x4D]); // bmp header 1
-    ret.append(&mut little_endian(4, file_size));
-    ret.extend([
-        0x00, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
-    ]); // bmp header 2
-    ret.append(&mut little_endian(4, ceil_width));
-    ret.append(&mut little_endian(4, ceil_height));
-    ret.extend([0x01, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00]); // bmp header 3
-    ret.append(&mut little_endian(4, size));
-    ret.extend([0x13, 0x0b, 0x00, 0x00, 0x13, 0x0b, 0x00, 0x00]);
-    ret.extend([0x00; 8]); // bmp header 4
-    for i in (0..ceil_height).rev() {
-        for j in 0..ceil_width {
-            let idx = i * ceil_width + j;
-            ret.push(img[idx].0);
-            ret.push(img[idx].1);
-            ret.push(img[idx].2);
-        }
-        ret.append(&mut vec![0; bytewidth - ceil_width * 3]);
+/// Version of [`try_write_text`] which writes the image into the given buffer instead
+/// of allocating one, returning the number of bytes written.
+///
+/// Use [`required_size`] to find out how large the buffer needs to be.
+pub fn write_text_into<T: AsRef<str>>(
+    text: T,
+    page: usize,
+    font: Font,
+    options: ImageOptions,
+    buf: &mut [u8],
+) -> Result<usize, Error> {
+    check_text(text.as_ref(), &font)?;
+    let page = Page::new(text.as_ref(), page, &font, &options)?;
+    let size = options.format.encoded_size(&page)?;
+    if buf.len() < size {
+        return Err(Error::BufferTooSmall {
+            required: size,
+            len: buf.len(),
+        });
     }
-    ret
+    let mut writer = SliceWriter::new(buf);
+    options.format.encode(&page, &mut writer)?;
+    Ok(writer.len)
 }
 
-fn little_endian(size: usize, data: usize) -> Vec<u8> {
-    let mut ret = Vec::new();
-    for i in 0..size {
-        ret.push((data >> (8 * i) & 0x000000ff) as u8);
-    }
-    ret
+/// Streaming version of [`try_write_text`].
+///
+/// The image is rendered in horizontal bands which are passed to `writer` as soon as
+/// they are done, so only a few rows are held in memory no matter how tall the image
+/// is. This is useful for rendering whole chapters at once. WebP output is
+/// the exception: it compresses the whole image at once, so it buffers all of it
+/// before writing anything.
+pub fn write_text_to<T: AsRef<str>, W: Write>(
+    text: T,
+    page: usize,
+    font: Font,
+    options: ImageOptions,
+    writer: &mut W,
+) -> Result<(), Error> {
+    check_text(text.as_ref(), &font)?;
+    let page = Page::new(text.as_ref(), page, &font, &options)?;
+    options.format.encode(&page, writer)
 }
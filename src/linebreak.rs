@@ -0,0 +1,172 @@
+use crate::vertical;
+use alloc::vec::Vec;
+
+/// Line breaking classes, named as in UAX #14.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+enum Class {
+    /// Alphabetic and other ordinary characters.
+    AL,
+    /// Break after, like hyphens other than the hyphen-minus.
+    BA,
+    /// Break before.
+    BB,
+    /// Break on either side, except between two of them, like em dashes.
+    B2,
+    /// Closing punctuation, including the CJK commas and full stops.
+    CL,
+    /// Combining mark.
+    CM,
+    /// Closing parenthesis.
+    CP,
+    /// Exclamation and interrogation.
+    EX,
+    /// Non-breaking glue.
+    GL,
+    /// Hyphen-minus.
+    HY,
+    /// Ideographic.
+    ID,
+    /// Inseparable, like ellipses.
+    IN,
+    /// Infix numeric separator.
+    IS,
+    /// Nonstarter, like small kana and the prolonged sound mark.
+    NS,
+    /// Numeric.
+    NU,
+    /// Opening punctuation.
+    OP,
+    /// Postfix numeric.
+    PO,
+    /// Prefix numeric.
+    PR,
+    /// Quotation.
+    QU,
+    /// Space.
+    SP,
+    /// Symbols allowing a break after, like the slash.
+    SY,
+    /// Word joiner.
+    WJ,
+    /// Zero width space.
+    ZW,
+}
+
+fn class(c: char) -> Class {
+    use Class::*;
+    match c {
+        ' ' => SP,
+        '\u{200B}' => ZW,
+        '\u{2060}' | '\u{FEFF}' => WJ,
+        '\u{00A0}' | '\u{034F}' | '\u{2007}' | '\u{2011}' | '\u{202F}' => GL,
+        '\t' | '\u{00AD}' | '\u{2010}' | '\u{2012}' | '\u{2013}' | '|' => BA,
+        '\u{2000}'..='\u{2006}' | '\u{2008}'..='\u{200A}' => BA,
+        '´' | '\u{02C8}' | '\u{02CC}' => BB,
+        '-' => HY,
+        '\u{2014}' => B2,
+        '\u{2024}'..='\u{2026}' => IN,
+        '!' | '?' | '\u{FF01}' | '\u{FF1F}' => EX,
+        ',' | '.' | ':' | ';' | '\u{037E}' | '\u{0589}' => IS,
+        '/' => SY,
+        '"' | '\'' | '«' | '»' | '\u{2018}' | '\u{2019}' | '\u{201B}'..='\u{201D}' => QU,
+        '\u{201F}' | '\u{2039}' | '\u{203A}' => QU,
+        '(' | '[' | '{' | '¡' | '¿' | '\u{201A}' | '\u{201E}' => OP,
+        '\u{3008}' | '\u{300A}' | '\u{300C}' | '\u{300E}' | '\u{3010}' | '\u{3014}' => OP,
+        '\u{3016}' | '\u{3018}' | '\u{301A}' | '\u{301D}' | '\u{FF08}' | '\u{FF3B}' => OP,
+        '\u{FF5B}' | '\u{FF5F}' | '\u{FF62}' => OP,
+        ')' | ']' => CP,
+        '}' | '\u{3001}' | '\u{3002}' | '\u{3009}' | '\u{300B}' | '\u{300D}' | '\u{300F}' => CL,
+        '\u{3011}' | '\u{3015}' | '\u{3017}' | '\u{3019}' | '\u{301B}' | '\u{301E}' => CL,
+        '\u{301F}' | '\u{FF09}' | '\u{FF0C}' | '\u{FF0E}' | '\u{FF3D}' | '\u{FF5D}' => CL,
+        '\u{FF60}' | '\u{FF61}' | '\u{FF63}' | '\u{FF64}' => CL,
+        // Small kana, iteration marks and the like, which may not start a line in
+        // Japanese (kinsoku).
+        'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'っ' | 'ゃ' | 'ゅ' | 'ょ' | 'ゎ' => NS,
+        'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ッ' | 'ャ' | 'ュ' | 'ョ' | 'ヮ' => NS,
+        'ゕ' | 'ゖ' | 'ヵ' | 'ヶ' => NS,
+        '\u{31F0}'..='\u{31FF}' | '\u{FF67}'..='\u{FF70}' => NS,
+        '々' | '〻' | '〜' | '゠' | 'ゝ' | 'ゞ' | '・' | 'ー' | 'ヽ' | 'ヾ' => NS,
+        '\u{203C}' | '\u{203D}' | '\u{2047}'..='\u{2049}' | '\u{FF1A}' | '\u{FF1B}' => NS,
+        '$' | '+' | '\\' | '£' | '¥' | '±' | '€' | '\u{2116}' | '\u{FF04}' => PR,
+        '%' | '¢' | '°' | '\u{2030}' | '\u{2032}'..='\u{2037}' | '\u{2103}' | '\u{FF05}' => PO,
+        '0'..='9' | '\u{0660}'..='\u{0669}' | '\u{06F0}'..='\u{06F9}' => NU,
+        '\u{0300}'..='\u{036F}'
+        | '\u{0483}'..='\u{0489}'
+        | '\u{0591}'..='\u{05BD}'
+        | '\u{064B}'..='\u{065F}'
+        | '\u{0670}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{3099}'
+        | '\u{309A}'
+        | '\u{FE20}'..='\u{FE2F}' => CM,
+        _ if vertical::is_wide(c) => ID,
+        _ => AL,
+    }
+}
+
+/// Byte offsets in a paragraph where a new line may start, following the core rules of
+/// UAX #14, including the Japanese rules against starting a line with closing
+/// punctuation or small kana. Spaces stay at the end of the line before a break.
+pub(crate) fn opportunities(text: &str) -> Vec<usize> {
+    let mut breaks = Vec::new();
+    let mut chars = text.char_indices();
+    let Some((_, first)) = chars.next() else {
+        return breaks;
+    };
+
+    // A combining mark at the start of the text or after a space stands on its own.
+    let standalone = |class| match class {
+        Class::CM => Class::AL,
+        class => class,
+    };
+    // The class of the previous character, and of the last one that wasn't a space.
+    let mut previous = standalone(class(first));
+    let mut before_spaces = previous;
+    let mut after_zw = previous == Class::ZW;
+    for (i, c) in chars {
+        let class = match class(c) {
+            // Combining marks take the class of the character they attach to.
+            Class::CM if !matches!(previous, Class::SP | Class::ZW) => continue,
+            class => standalone(class),
+        };
+        if allowed(previous, before_spaces, class, after_zw) {
+            breaks.push(i);
+        }
+
+        after_zw = class == Class::ZW || (after_zw && class == Class::SP);
+        previous = class;
+        if class != Class::SP {
+            before_spaces = class;
+        }
+    }
+    breaks
+}
+
+/// Whether a line may break between characters of the classes `before` and `after`,
+/// where `before_spaces` is the class of the last character before any spaces.
+fn allowed(before: Class, before_spaces: Class, after: Class, after_zw: bool) -> bool {
+    use Class::*;
+    match (before, after) {
+        (_, SP | ZW) => false,
+        _ if after_zw => true,
+        (WJ, _) | (_, WJ) | (GL, _) => false,
+        (SP | BA | HY, GL) => true,
+        (_, GL) => false,
+        (_, CL | CP | EX | IS | SY) => false,
+        _ if before_spaces == OP => false,
+        _ if before_spaces == QU && after == OP => false,
+        _ if matches!(before_spaces, CL | CP) && after == NS => false,
+        _ if before_spaces == B2 && after == B2 => false,
+        (SP, _) => true,
+        (QU, _) | (_, QU) => false,
+        (_, BA | HY | NS | IN) | (BB, _) => false,
+        (AL, NU) | (NU, AL) => false,
+        (PR, ID) | (ID, PO) => false,
+        (PR | PO, AL) | (AL, PR | PO) => false,
+        (CL | CP | NU, PO | PR) | (PO | PR, OP | NU) | (HY | IS | NU | SY, NU) => false,
+        (AL, AL) | (IS, AL) => false,
+        (AL | NU, OP) | (CP, AL | NU) => false,
+        _ => true,
+    }
+}
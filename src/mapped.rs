@@ -0,0 +1,64 @@
+use alloc::{string::String, vec::Vec};
+
+/// Text made from another one, remembering where in the other text each byte came from
+/// when asked to, so that spans given over the text passed in can be found after it has
+/// been transliterated and made typographic.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Mapped {
+    pub text: String,
+    /// For each byte of the text and one past its end, the offset of the character of the
+    /// other text it came from. `None` when not tracked.
+    pub origins: Option<Vec<usize>>,
+}
+
+impl Mapped {
+    /// An empty text, tracking where its bytes come from if `track` is set.
+    pub fn new(capacity: usize, track: bool) -> Self {
+        Self {
+            text: String::with_capacity(capacity),
+            origins: track.then(|| Vec::with_capacity(capacity + 1)),
+        }
+    }
+
+    /// Add a character that came from the given offset.
+    pub fn push(&mut self, c: char, origin: usize) {
+        self.text.push(c);
+        if let Some(origins) = &mut self.origins {
+            origins.extend((0..c.len_utf8()).map(|_| origin));
+        }
+    }
+
+    /// Add characters that all came from the given offset.
+    pub fn push_str(&mut self, s: &str, origin: usize) {
+        self.text.push_str(s);
+        if let Some(origins) = &mut self.origins {
+            origins.extend((0..s.len()).map(|_| origin));
+        }
+    }
+
+    /// Shorten the text to `len` bytes.
+    pub fn truncate(&mut self, len: usize) {
+        self.text.truncate(len);
+        if let Some(origins) = &mut self.origins {
+            origins.truncate(len);
+        }
+    }
+
+    /// Mark the end of the text as coming from the end of the other one, `len` bytes
+    /// long.
+    pub fn finish(mut self, len: usize) -> Self {
+        if let Some(origins) = &mut self.origins {
+            origins.push(len);
+        }
+        self
+    }
+
+    /// Follow the offsets back one step further, when the other text was itself made
+    /// from a third one with the given origins.
+    pub fn through(mut self, origins: Option<&[usize]>) -> Self {
+        if let (Some(own), Some(origins)) = (&mut self.origins, origins) {
+            own.iter_mut().for_each(|i| *i = origins[*i]);
+        }
+        self
+    }
+}
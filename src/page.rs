@@ -0,0 +1,430 @@
+use crate::{
+    bidi,
+    decoration::{self, Mark},
+    fonts::{Font, SUBPIXELS},
+    heads::Head,
+    layout::{self, Line, Metrics},
+    raster::{Canvas, Glyphs},
+    resample::{resample, resample_bgr},
+    split_color, Background, BitmapPixel, Decoration, Error, Frame, ImageOptions, WritingMode,
+};
+use alloc::{string::String, vec, vec::Vec};
+use core::{cell::OnceCell, ops::Range};
+
+/// Number of rows drawn at once by [`Page::rows`].
+const BAND_HEIGHT: usize = 64;
+
+/// A page of text that has been laid out and is ready to be drawn.
+pub(crate) struct Page<'a> {
+    font: &'a Font,
+    glyphs: Glyphs<'a>,
+    lines: Vec<Line>,
+    /// Vertical position of each line on the page, or horizontal position of the left
+    /// edge of each column of vertical text.
+    tops: Vec<usize>,
+    /// Position of the start of each line or column along it, in 1/64 of a pixel.
+    starts: Vec<usize>,
+    pub width: usize,
+    pub height: usize,
+    /// Padding after the end of each line or column.
+    padding_end: usize,
+    /// The lines in each column of text, and where the column ends on the right.
+    columns: Vec<(Range<usize>, usize)>,
+    writing_mode: WritingMode,
+    /// Running header and footer.
+    heads: Vec<Head<'a>>,
+    /// Glyphs of the title, if the page has one.
+    title_glyphs: Option<Glyphs<'a>>,
+    /// Lines of the title, each with its left edge in 1/64 of a pixel and its top.
+    title: Vec<(String, (usize, usize))>,
+    /// Bitmap of the drop cap, its width and the position of its top left corner.
+    drop_cap: Option<(Vec<u8>, usize, (usize, usize))>,
+    background: Option<Background<'a>>,
+    frame: Option<Frame>,
+    /// Lines drawn as a rule between scenes.
+    scene_break: Option<&'a str>,
+    /// Illustrations on the page scaled to size, by the index of their line.
+    figures: Vec<(usize, Vec<u8>)>,
+    /// Decorations along the text, in the order of their lines.
+    marks: Vec<Mark>,
+    /// Distinct colors of the page, see [`Page::colors`].
+    colors: OnceCell<Option<Vec<[u8; 3]>>>,
+    pub text_color: BitmapPixel,
+    pub background_color: BitmapPixel,
+}
+
+impl<'a> Page<'a> {
+    /// Lay out the text and pick the lines on the given page. Page 0 contains all text.
+    pub fn new(
+        text: &str,
+        page: usize,
+        font: &'a Font,
+        options: &'a ImageOptions,
+    ) -> Result<Self, Error> {
+        options.validate(font)?;
+
+        let (mut lines, metrics) = layout::flow(text, font, options);
+        // The first line of text, which may come after illustrations.
+        let lead = lines.iter().position(|l| l.figure.is_none()).unwrap_or(0);
+        let title = options
+            .title
+            .as_ref()
+            .filter(|_| options.writing_mode == WritingMode::Horizontal);
+        let title_font = title.map(|title| &title.font);
+        let scene_break = options
+            .scene_break
+            .map(str::trim)
+            .filter(|marker| !marker.is_empty() && options.writing_mode == WritingMode::Horizontal);
+        let title_lines = title.map_or(Vec::new(), |title| {
+            layout::title_lines(title, metrics.text_width())
+        });
+        let max_width = lines
+            .iter()
+            .map(|l| (l.indent + l.advance).div_ceil(SUBPIXELS))
+            .chain(title_lines.iter().map(Line::width))
+            .max()
+            .unwrap_or(0);
+        // Index of the first line of the page in the whole text, and the lines of each
+        // column of the page.
+        let mut first = 0;
+        let mut page_count = 0;
+        let columns = if page >= 1 {
+            let columns = layout::columns(&lines, &metrics);
+            let pages: Vec<_> = columns.chunks(metrics.columns).collect();
+            page_count = pages.len();
+            let columns = pages.get(page - 1).ok_or(Error::PageOutOfRange {
+                page,
+                pages: pages.len(),
+            })?;
+            let range = columns[0].start..columns[columns.len() - 1].end;
+            lines.truncate(range.end);
+            lines.drain(..range.start);
+            first = range.start;
+            columns
+                .iter()
+                .map(|c| c.start - first..c.end - first)
+                .collect()
+        } else {
+            layout::balance(0..lines.len(), metrics.columns)
+        };
+        // The title sits above the text of the first page.
+        let title_height = match page {
+            0 | 1 => metrics.title_height,
+            _ => 0,
+        };
+        let mut offsets = Vec::with_capacity(lines.len());
+        let mut text_height = 0;
+        for column in &columns {
+            offsets.extend(layout::line_offsets(&lines[column.clone()], &metrics));
+            text_height = text_height.max(layout::text_height(&lines[column.clone()], &metrics));
+        }
+        let needed = text_height + metrics.padding_height() + title_height;
+        let mut glyphs = Glyphs::new(font);
+        let mut starts = Vec::with_capacity(lines.len());
+        let mut ends = Vec::with_capacity(columns.len());
+        let mut marks = Vec::new();
+        let highlights = options.highlights.map(|h| h.spans(text));
+        let spans = [options.spans, highlights.as_deref().unwrap_or(&[])].concat();
+
+        let (tops, width, height): (Vec<usize>, _, _) = match options.writing_mode {
+            WritingMode::Horizontal => {
+                let width = if options.constant_width || metrics.columns > 1 {
+                    metrics.width
+                } else {
+                    max_width + metrics.padding_left + metrics.padding_right
+                };
+                // Lines are drawn in visual order, right to left paragraphs from the
+                // right edge of their column.
+                for (i, column) in columns.iter().enumerate() {
+                    let left = metrics.column_left(i);
+                    let right = match metrics.columns {
+                        1 => width - metrics.padding_right,
+                        _ => left + metrics.column_width(),
+                    };
+                    let (start, end) = (left * SUBPIXELS, right * SUBPIXELS);
+                    for (i, line) in lines[column.clone()].iter_mut().enumerate() {
+                        if line.figure.is_some() {
+                            starts.push(start + (end - start).saturating_sub(line.advance) / 2);
+                            continue;
+                        }
+                        if scene_break == Some(line.text.trim()) {
+                            starts.push(start);
+                            continue;
+                        }
+                        let (text, order) = bidi::reorder_mapped(&line.text, line.rtl);
+                        let x = match line.rtl {
+                            true => end.saturating_sub(line.advance).max(start),
+                            false => start + line.indent,
+                        };
+                        if !line.origins.is_empty() {
+                            let logical: Vec<usize> = line
+                                .text
+                                .char_indices()
+                                .map(|(i, _)| line.origins[i])
+                                .collect();
+                            let origins: Vec<usize> = order.iter().map(|&i| logical[i]).collect();
+                            let line = (column.start + i, text.as_str(), x);
+                            decoration::find_marks(line, &origins, font, &spans, &mut marks);
+                        }
+                        line.text = text;
+                        glyphs.prepare(&line.text, x);
+                        starts.push(x);
+                    }
+                    ends.push(right);
+                }
+                let top = metrics.padding_top + title_height;
+                let tops = offsets.iter().map(|y| y + top).collect();
+                (tops, width, needed)
+            }
+            WritingMode::VerticalRl => {
+                for line in &lines {
+                    glyphs.prepare_vertical(&line.text);
+                    starts.push(metrics.padding_left * SUBPIXELS);
+                }
+                // The metrics are turned across the page, so the columns are laid out
+                // like lines and then placed from the right edge.
+                let width = match metrics.page_height {
+                    Some(page_width) if options.constant_width => needed.max(page_width),
+                    _ => needed,
+                };
+                let tops = offsets
+                    .iter()
+                    .map(|x| width - metrics.padding_top - x - metrics.line_height)
+                    .collect();
+                ends.push(width);
+                (tops, width, metrics.width)
+            }
+        };
+        let columns = columns.into_iter().zip(ends).collect();
+
+        // Running heads are laid out on the upright page, even for vertical text.
+        let mut heads = Vec::new();
+        let upright = Metrics::new(font, options);
+        let across = upright.padding_left..width.saturating_sub(upright.padding_right);
+        if page >= 1 {
+            if let Some(header) = &options.header {
+                let rows = 0..upright.padding_top;
+                heads.push(Head::new(header, across.clone(), rows, (page, page_count)));
+            }
+            if let Some(footer) = &options.footer {
+                let rows = height.saturating_sub(upright.padding_bottom)..height;
+                heads.push(Head::new(footer, across, rows, (page, page_count)));
+            }
+        }
+
+        // The title is centered above the text.
+        let mut title_glyphs = None;
+        let mut title = Vec::new();
+        if let Some(font) = title_font.filter(|_| page <= 1) {
+            let mut glyphs = Glyphs::new(font);
+            let text_width = width.saturating_sub(metrics.padding_left + metrics.padding_right);
+            for (i, line) in title_lines.into_iter().enumerate() {
+                let free = (text_width * SUBPIXELS).saturating_sub(line.advance);
+                let x = metrics.padding_left * SUBPIXELS + free / 2;
+                glyphs.prepare(&line.text, x);
+                let top = metrics.padding_top + i * font.line_height();
+                title.push((line.text, (x, top)));
+            }
+            title_glyphs = Some(glyphs);
+        }
+
+        // The drop cap is drawn beside the first lines, on the page they start on.
+        let on_page = lead.checked_sub(first).filter(|&i| i < lines.len());
+        let drop_cap = metrics.drop_cap.zip(on_page).map(|(cap, lead)| {
+            let (font, c) = (&cap.font, cap.letter);
+            let width = font.scaled_width(c);
+            let glyph = resample(
+                font.glyph(c),
+                (font.glyph_width(c), font.bitmap_height()),
+                (width, font.line_height()),
+            );
+            let glyph = glyph[(cap.overhang * width).min(glyph.len())..].to_vec();
+            (glyph, width, (metrics.padding_left, tops[lead]))
+        });
+
+        let mut figures = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(figure) = line.figure {
+                let illustration = &options.illustrations[figure.index];
+                let pixels = resample_bgr(
+                    illustration.pixels,
+                    (illustration.width, illustration.height),
+                    (figure.width, figure.height),
+                );
+                figures.push((i, pixels));
+            }
+        }
+
+        Ok(Self {
+            font,
+            glyphs,
+            lines,
+            tops,
+            starts,
+            width,
+            height,
+            padding_end: metrics.padding_right,
+            columns,
+            writing_mode: options.writing_mode,
+            heads,
+            title_glyphs,
+            title,
+            drop_cap,
+            background: options.background,
+            frame: options.frame,
+            scene_break,
+            figures,
+            marks,
+            colors: OnceCell::new(),
+            text_color: split_color(options.text_color),
+            background_color: split_color(options.background_color),
+        })
+    }
+
+    /// Whether the page is drawn only in the text and background colors and shades
+    /// between them.
+    pub fn is_two_tone(&self) -> bool {
+        let highlighted = self
+            .marks
+            .iter()
+            .any(|mark| matches!(mark.decoration, Decoration::Highlight(_)));
+        self.background.is_none() && self.frame.is_none() && self.figures.is_empty() && !highlighted
+    }
+
+    /// The distinct colors of the page, sorted, unless there are more than 256. The page
+    /// is drawn to find them the first time they are asked for.
+    pub fn colors(&self) -> Option<&[[u8; 3]]> {
+        let colors = self.colors.get_or_init(|| {
+            let mut colors = Vec::new();
+            let mut last = None;
+            let mut too_many = false;
+            let _ = self.rows(false, |row| {
+                for pixel in row.chunks_exact(3) {
+                    if too_many {
+                        break;
+                    }
+                    let pixel = [pixel[0], pixel[1], pixel[2]];
+                    if last == Some(pixel) {
+                        continue;
+                    }
+                    last = Some(pixel);
+                    if let Err(i) = colors.binary_search(&pixel) {
+                        too_many = colors.len() == 256;
+                        colors.insert(i, pixel);
+                    }
+                }
+                Ok(())
+            });
+            (!too_many).then_some(colors)
+        });
+        colors.as_deref()
+    }
+
+    /// Draw the part of the page covered by the canvas.
+    pub fn draw(&self, canvas: &mut Canvas) {
+        canvas.fill(self.background_color);
+        if let Some(background) = &self.background {
+            decoration::draw_background(canvas, background, (self.width, self.height));
+        }
+        if let Some(frame) = &self.frame {
+            decoration::draw_frame(canvas, frame, (self.width, self.height));
+        }
+        for head in &self.heads {
+            head.draw(canvas, self.text_color, self.background_color);
+        }
+        if let Some(glyphs) = &self.title_glyphs {
+            for (text, position) in &self.title {
+                let max_x = self.width - self.padding_end;
+                canvas.draw_text(text, *position, max_x, glyphs, self.text_color);
+            }
+        }
+        if let Some((glyph, width, position)) = &self.drop_cap {
+            canvas.draw_glyph(glyph, *width, *position, self.text_color);
+        }
+
+        let line_height = self.font.line_height();
+        if self.writing_mode == WritingMode::VerticalRl {
+            for ((line, &left), &start) in self.lines.iter().zip(&self.tops).zip(&self.starts) {
+                canvas.draw_column(
+                    &line.text,
+                    (left, start),
+                    (line_height, self.height - self.padding_end),
+                    &self.glyphs,
+                    self.text_color,
+                );
+            }
+            return;
+        }
+
+        let rows = canvas.rows();
+        let tallest = self.lines.iter().map(|l| l.height(line_height)).max();
+        let tallest = tallest.unwrap_or(line_height);
+        for (column, max_x) in &self.columns {
+            let tops = &self.tops[column.clone()];
+            let first = tops.partition_point(|&top| top + tallest <= rows.start);
+            let last = tops.partition_point(|&top| top < rows.end);
+            for i in column.start + first..column.start + last {
+                if let Some(figure) = self.lines[i].figure {
+                    let (_, pixels) = self.figures.iter().find(|(line, _)| *line == i).unwrap();
+                    let x = self.starts[i] / SUBPIXELS;
+                    canvas.draw_image(pixels, figure.width, (x, self.tops[i]));
+                    continue;
+                }
+                if self.scene_break == Some(self.lines[i].text.trim()) {
+                    self.draw_rule((self.starts[i], self.tops[i]), *max_x, canvas);
+                    continue;
+                }
+                let from = self.marks.partition_point(|mark| mark.line < i);
+                let to = self.marks.partition_point(|mark| mark.line <= i);
+                let marks = &self.marks[from..to];
+                let at = (self.tops[i], *max_x);
+                decoration::draw_highlights(canvas, marks, at, line_height);
+                canvas.draw_text(
+                    &self.lines[i].text,
+                    (self.starts[i], self.tops[i]),
+                    *max_x,
+                    &self.glyphs,
+                    self.text_color,
+                );
+                decoration::draw_lines(canvas, marks, at, self.font, self.text_color);
+            }
+        }
+    }
+
+    /// Draw a short rule centered on a line starting at `(x, y)`, where `x` is in 1/64 of
+    /// a pixel, that ends at `max_x`.
+    fn draw_rule(&self, (x, y): (usize, usize), max_x: usize, canvas: &mut Canvas) {
+        let line_height = self.font.line_height();
+        let (left, right) = (x / SUBPIXELS, max_x);
+        let length = right.saturating_sub(left) / 4;
+        let thickness = (line_height / 12).max(1);
+        let left = left + (right.saturating_sub(left) - length) / 2;
+        let top = y + (line_height - thickness) / 2;
+        canvas.fill_rect(left..left + length, top..top + thickness, self.text_color);
+    }
+
+    /// Draw the page a band at a time, passing each row of 24-bit BGR pixels to `f`,
+    /// from the top of the page or from the bottom if `bottom_up` is set.
+    pub fn rows(
+        &self,
+        bottom_up: bool,
+        mut f: impl FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let stride = self.width * 3;
+        let mut buf = vec![0; stride * BAND_HEIGHT.min(self.height)];
+        let bands = self.height.div_ceil(BAND_HEIGHT);
+        for i in 0..bands {
+            let band = if bottom_up { bands - 1 - i } else { i };
+            let rows = band * BAND_HEIGHT..((band + 1) * BAND_HEIGHT).min(self.height);
+            let data = &mut buf[..stride * rows.len()];
+            self.draw(&mut Canvas::new(data, self.width, rows));
+
+            if bottom_up {
+                data.chunks_exact(stride).rev().try_for_each(&mut f)?;
+            } else {
+                data.chunks_exact(stride).try_for_each(&mut f)?;
+            }
+        }
+        Ok(())
+    }
+}
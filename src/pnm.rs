@@ -0,0 +1,60 @@
+use crate::{page::Page, raster::luma, Error, Write};
+use alloc::{format, string::String, vec};
+
+/// Netpbm formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// PPM, 24-bit RGB.
+    Pixmap,
+    /// PGM, 8-bit grayscale.
+    Graymap,
+}
+
+impl Kind {
+    fn header(self, width: usize, height: usize) -> String {
+        let magic = match self {
+            Kind::Pixmap => "P6",
+            Kind::Graymap => "P5",
+        };
+        format!("{magic}\n{width} {height}\n255\n")
+    }
+
+    fn bytes_per_pixel(self) -> usize {
+        match self {
+            Kind::Pixmap => 3,
+            Kind::Graymap => 1,
+        }
+    }
+}
+
+/// Get the size of the file for an image of the given size.
+pub(crate) fn encoded_size(kind: Kind, width: usize, height: usize) -> Result<usize, Error> {
+    width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(kind.bytes_per_pixel()))
+        .and_then(|size| size.checked_add(kind.header(width, height).len()))
+        .ok_or(Error::ImageTooLarge { width, height })
+}
+
+/// Encode the page as a binary PPM or PGM file.
+pub(crate) fn encode<W: Write>(kind: Kind, page: &Page, writer: &mut W) -> Result<(), Error> {
+    encoded_size(kind, page.width, page.height)?;
+    writer.write_all(kind.header(page.width, page.height).as_bytes())?;
+
+    let mut row = vec![0; page.width * kind.bytes_per_pixel()];
+    page.rows(false, |pixels| {
+        match kind {
+            Kind::Pixmap => {
+                for (out, pixel) in row.chunks_exact_mut(3).zip(pixels.chunks_exact(3)) {
+                    out.copy_from_slice(&[pixel[2], pixel[1], pixel[0]]);
+                }
+            }
+            Kind::Graymap => {
+                for (out, pixel) in row.iter_mut().zip(pixels.chunks_exact(3)) {
+                    *out = luma(pixel);
+                }
+            }
+        }
+        writer.write_all(&row)
+    })
+}
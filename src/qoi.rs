@@ -0,0 +1,119 @@
+use crate::{page::Page, Error, Write};
+use alloc::vec::Vec;
+
+const HEADER_SIZE: usize = 14;
+const END_MARKER: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const OP_RUN: u8 = 0xC0;
+const OP_RGB: u8 = 0xFE;
+
+/// Longest run a single chunk can encode.
+const MAX_RUN: u8 = 62;
+
+/// Get the size of the QOI file for an image of the given size, in the worst case
+/// where every pixel needs a full chunk.
+pub(crate) fn encoded_size(width: usize, height: usize) -> Result<usize, Error> {
+    if width > u32::MAX as usize || height > u32::MAX as usize {
+        return Err(Error::ImageTooLarge { width, height });
+    }
+    width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(4))
+        .and_then(|size| size.checked_add(HEADER_SIZE + END_MARKER.len()))
+        .ok_or(Error::ImageTooLarge { width, height })
+}
+
+/// Encode the page as a QOI file.
+pub(crate) fn encode<W: Write>(page: &Page, writer: &mut W) -> Result<(), Error> {
+    encoded_size(page.width, page.height)?;
+
+    let mut header = [0; HEADER_SIZE];
+    header[..4].copy_from_slice(b"qoif");
+    header[4..8].copy_from_slice(&(page.width as u32).to_be_bytes());
+    header[8..12].copy_from_slice(&(page.height as u32).to_be_bytes());
+    header[12] = 3; // RGB
+    header[13] = 0; // sRGB with linear alpha
+    writer.write_all(&header)?;
+
+    let mut encoder = Encoder::new();
+    let mut out = Vec::with_capacity(page.width * 4);
+    page.rows(false, |pixels| {
+        out.clear();
+        for pixel in pixels.chunks_exact(3) {
+            encoder.push([pixel[2], pixel[1], pixel[0], 0xFF], &mut out);
+        }
+        writer.write_all(&out)
+    })?;
+
+    out.clear();
+    encoder.finish(&mut out);
+    out.extend_from_slice(&END_MARKER);
+    writer.write_all(&out)
+}
+
+/// QOI encoder state, carried over from one row to the next.
+pub(crate) struct Encoder {
+    previous: [u8; 4],
+    /// Recently seen pixels, indexed by their hash.
+    seen: [[u8; 4]; 64],
+    run: u8,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self {
+            previous: [0, 0, 0, 0xFF],
+            seen: [[0; 4]; 64],
+            run: 0,
+        }
+    }
+
+    pub fn push(&mut self, pixel: [u8; 4], out: &mut Vec<u8>) {
+        if pixel == self.previous {
+            self.run += 1;
+            if self.run == MAX_RUN {
+                self.finish(out);
+            }
+            return;
+        }
+        self.finish(out);
+
+        let [r, g, b, a] = pixel;
+        let hash = (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64;
+        if self.seen[hash] == pixel {
+            out.push(OP_INDEX | hash as u8);
+        } else {
+            self.seen[hash] = pixel;
+
+            let dr = r.wrapping_sub(self.previous[0]) as i8;
+            let dg = g.wrapping_sub(self.previous[1]) as i8;
+            let db = b.wrapping_sub(self.previous[2]) as i8;
+            let dr_dg = dr.wrapping_sub(dg);
+            let db_dg = db.wrapping_sub(dg);
+
+            if (-2..2).contains(&dr) && (-2..2).contains(&dg) && (-2..2).contains(&db) {
+                out.push(OP_DIFF | ((dr + 2) << 4 | (dg + 2) << 2 | (db + 2)) as u8);
+            } else if (-32..32).contains(&dg)
+                && (-8..8).contains(&dr_dg)
+                && (-8..8).contains(&db_dg)
+            {
+                out.push(OP_LUMA | (dg + 32) as u8);
+                out.push(((dr_dg + 8) << 4 | (db_dg + 8)) as u8);
+            } else {
+                out.extend_from_slice(&[OP_RGB, r, g, b]);
+            }
+        }
+        self.previous = pixel;
+    }
+
+    /// Write out the pending run, if any.
+    pub fn finish(&mut self, out: &mut Vec<u8>) {
+        if self.run > 0 {
+            out.push(OP_RUN | (self.run - 1));
+            self.run = 0;
+        }
+    }
+}
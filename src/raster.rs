@@ -0,0 +1,338 @@
+use crate::{
+    fonts::{Font, SUBPIXELS},
+    resample::resample,
+    vertical::{self, Orientation},
+    BitmapPixel,
+};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::ops::Range;
+
+/// Number of sub-pixel positions glyphs are rendered at.
+const PHASES: usize = 4;
+
+/// A 24-bit BGR image that text is drawn onto, stored row by row from the top.
+///
+/// The canvas may only hold a horizontal band of the image, in which case anything
+/// outside of it is clipped.
+pub(crate) struct Canvas<'a> {
+    data: &'a mut [u8],
+    width: usize,
+    /// The rows of the image held by the canvas.
+    rows: Range<usize>,
+}
+
+impl<'a> Canvas<'a> {
+    /// Wrap a pixel buffer holding the given rows of the image.
+    pub fn new(data: &'a mut [u8], width: usize, rows: Range<usize>) -> Self {
+        debug_assert_eq!(data.len(), width * 3 * rows.len());
+        Self { data, width, rows }
+    }
+
+    /// The rows of the image held by the canvas.
+    pub fn rows(&self) -> Range<usize> {
+        self.rows.clone()
+    }
+
+    /// The pixel bytes of row `y`, counting from the top of the image.
+    pub fn row(&mut self, y: usize) -> &mut [u8] {
+        let start = (y - self.rows.start) * self.width * 3;
+        &mut self.data[start..start + self.width * 3]
+    }
+
+    /// Paint every pixel with the given color.
+    pub fn fill(&mut self, color: BitmapPixel) {
+        for pixel in self.data.chunks_exact_mut(3) {
+            pixel.copy_from_slice(&[color.0, color.1, color.2]);
+        }
+    }
+
+    /// Paint a rectangle with the given color.
+    pub fn fill_rect(&mut self, columns: Range<usize>, rows: Range<usize>, color: BitmapPixel) {
+        let columns = columns.start.min(self.width)..columns.end.min(self.width);
+        for y in rows.start.max(self.rows.start)..rows.end.min(self.rows.end) {
+            let row = &mut self.row(y)[columns.start * 3..columns.end * 3];
+            for pixel in row.chunks_exact_mut(3) {
+                pixel.copy_from_slice(&[color.0, color.1, color.2]);
+            }
+        }
+    }
+
+    /// Copy a 24-bit image `width` pixels wide with its top left corner at `(x, y)`.
+    pub fn draw_image(&mut self, pixels: &[u8], width: usize, (x, y): (usize, usize)) {
+        if x >= self.width || width == 0 {
+            return;
+        }
+        let bottom = (y + pixels.len() / (width * 3)).min(self.rows.end);
+        let visible = width.min(self.width - x);
+        for image_y in y.max(self.rows.start)..bottom {
+            let row = &pixels[(image_y - y) * width * 3..][..visible * 3];
+            self.row(image_y)[x * 3..(x + visible) * 3].copy_from_slice(row);
+        }
+    }
+
+    /// Draw a line of text with its top left corner at `(x, y)`, where `x` is in 1/64
+    /// of a pixel. Anything past `max_x` is clipped.
+    pub fn draw_text(
+        &mut self,
+        text: &str,
+        (x, y): (usize, usize),
+        max_x: usize,
+        glyphs: &Glyphs,
+        color: BitmapPixel,
+    ) {
+        let line_height = glyphs.font.line_height();
+        let max_x = max_x.min(self.width);
+        let rows = y.max(self.rows.start)..(y + line_height).min(self.rows.end);
+        if rows.is_empty() {
+            return;
+        }
+
+        // Glyphs at sub-pixel positions can share a column, where their coverage adds
+        // up, so each row is gathered before it is painted.
+        let mut placed = Vec::new();
+        let mut pen = x;
+        for c in text.chars() {
+            let (left, phase) = place(pen);
+            if left >= max_x {
+                break;
+            }
+            let (glyph, width) = glyphs.get(c, phase);
+            placed.push((left, glyph, width, width.min(max_x - left)));
+            pen += glyphs.font.advance(c);
+        }
+        let Some(start) = placed.first().map(|&(left, ..)| left) else {
+            return;
+        };
+        let end = placed
+            .iter()
+            .map(|&(left, _, _, visible)| left + visible)
+            .max()
+            .unwrap_or(start);
+
+        let mut coverage = vec![0u8; end - start];
+        for image_y in rows {
+            let row = image_y - y;
+            coverage.fill(0);
+            let mut filled = 0usize;
+            for &(left, glyph, width, visible) in &placed {
+                let (left, glyph) = (left - start, &glyph[row * width..row * width + visible]);
+                // Only the start of a glyph can overlap the previous one.
+                let overlap = filled.saturating_sub(left).min(visible);
+                for (out, &alpha) in coverage[left..].iter_mut().zip(&glyph[..overlap]) {
+                    *out = out.saturating_add(alpha);
+                }
+                coverage[left + overlap..left + visible].copy_from_slice(&glyph[overlap..]);
+                filled = filled.max(left + visible);
+            }
+            blit_row(&mut self.row(image_y)[start * 3..end * 3], &coverage, color);
+        }
+    }
+
+    /// Draw a column of vertical text `pitch` pixels wide, with its top left corner at
+    /// `(x, y)`, where `y` is in 1/64 of a pixel. Anything below `max_y` is clipped.
+    pub fn draw_column(
+        &mut self,
+        text: &str,
+        (x, y): (usize, usize),
+        (pitch, max_y): (usize, usize),
+        glyphs: &Glyphs,
+        color: BitmapPixel,
+    ) {
+        let mut pen = y;
+        for c in text.chars() {
+            let top = (pen + SUBPIXELS / 2) / SUBPIXELS;
+            if top >= max_y.min(self.rows.end) {
+                break;
+            }
+            pen += vertical::advance(glyphs.font, c);
+            let (glyph, width, height) = glyphs.vertical(c);
+            let centered = x + pitch.saturating_sub(width) / 2;
+            let (left, top) = match vertical::orientation(c) {
+                Orientation::Upright => (centered, top),
+                Orientation::Sideways => (x, top),
+                Orientation::Raised => (centered + width / 2, top.saturating_sub(height / 2)),
+            };
+            let rows = height.min(max_y.saturating_sub(top));
+            self.draw_glyph(&glyph[..rows * width], width, (left, top), color);
+        }
+    }
+
+    /// Draw a glyph bitmap `width` pixels wide with its top left corner at `(x, y)`.
+    pub fn draw_glyph(
+        &mut self,
+        glyph: &[u8],
+        width: usize,
+        (x, y): (usize, usize),
+        color: BitmapPixel,
+    ) {
+        if x >= self.width || width == 0 {
+            return;
+        }
+        let bottom = (y + glyph.len() / width).min(self.rows.end);
+        let visible = width.min(self.width - x);
+        for image_y in y.max(self.rows.start)..bottom {
+            let row = &glyph[(image_y - y) * width..][..visible];
+            blit_row(&mut self.row(image_y)[x * 3..], row, color);
+        }
+    }
+}
+
+/// The glyphs of a font resampled to its scale, along with copies shifted by fractions
+/// of a pixel for drawing them at sub-pixel positions.
+pub(crate) struct Glyphs<'a> {
+    font: &'a Font,
+    /// Resampled glyphs by character, unless the font is drawn as it is.
+    scaled: BTreeMap<char, Vec<u8>>,
+    /// Shifted glyphs by character and phase, one pixel wider than the unshifted ones.
+    shifted: BTreeMap<(char, usize), Vec<u8>>,
+    /// Glyphs turned 90° clockwise for vertical text, by character.
+    rotated: BTreeMap<char, Vec<u8>>,
+}
+
+impl<'a> Glyphs<'a> {
+    pub fn new(font: &'a Font) -> Self {
+        Self {
+            font,
+            scaled: BTreeMap::new(),
+            shifted: BTreeMap::new(),
+            rotated: BTreeMap::new(),
+        }
+    }
+
+    /// Render the glyphs needed to draw the text starting at `x`, in 1/64 of a pixel.
+    pub fn prepare(&mut self, text: &str, x: usize) {
+        let font = self.font;
+        let mut pen = x;
+        for c in text.chars() {
+            self.scale(c);
+            let (_, phase) = place(pen);
+            if phase > 0 && !self.shifted.contains_key(&(c, phase)) {
+                let (glyph, width) = self.get(c, 0);
+                let shifted = shift(glyph, width, font.line_height(), phase);
+                self.shifted.insert((c, phase), shifted);
+            }
+            pen += font.advance(c);
+        }
+    }
+
+    /// Render the glyphs needed to draw a column of vertical text.
+    pub fn prepare_vertical(&mut self, text: &str) {
+        for c in text.chars() {
+            self.scale(c);
+            if vertical::orientation(c) == Orientation::Sideways && !self.rotated.contains_key(&c) {
+                let (glyph, width) = self.get(c, 0);
+                let rotated = rotate(glyph, width, self.font.line_height());
+                self.rotated.insert(c, rotated);
+            }
+        }
+    }
+
+    fn scale(&mut self, c: char) {
+        let font = self.font;
+        if !font.is_unscaled() {
+            self.scaled.entry(c).or_insert_with(|| {
+                resample(
+                    font.glyph(c),
+                    (font.glyph_width(c), font.bitmap_height()),
+                    (font.scaled_width(c), font.line_height()),
+                )
+            });
+        }
+    }
+
+    /// Bitmap, width and height of the glyph for the given character as it is set in
+    /// vertical text, which must have been prepared.
+    fn vertical(&self, c: char) -> (&[u8], usize, usize) {
+        let line_height = self.font.line_height();
+        match vertical::orientation(c) {
+            Orientation::Upright | Orientation::Raised => {
+                let (glyph, width) = self.get(c, 0);
+                (glyph, width, line_height)
+            }
+            Orientation::Sideways => (&self.rotated[&c], line_height, self.width(c)),
+        }
+    }
+
+    fn width(&self, c: char) -> usize {
+        if self.font.is_unscaled() {
+            self.font.glyph_width(c)
+        } else {
+            self.font.scaled_width(c)
+        }
+    }
+
+    /// Bitmap and width of the glyph for the given character at the given phase, which
+    /// must have been prepared.
+    fn get(&self, c: char, phase: usize) -> (&[u8], usize) {
+        let glyph = if self.font.is_unscaled() {
+            self.font.glyph(c)
+        } else {
+            &self.scaled[&c][..]
+        };
+        let width = self.width(c);
+        match phase {
+            0 => (glyph, width),
+            _ => (&self.shifted[&(c, phase)], width + 1),
+        }
+    }
+}
+
+/// The pixel column and phase a glyph at `x`, in 1/64 of a pixel, is drawn at.
+fn place(x: usize) -> (usize, usize) {
+    let phase = (x % SUBPIXELS * PHASES + SUBPIXELS / 2) / SUBPIXELS;
+    (x / SUBPIXELS + phase / PHASES, phase % PHASES)
+}
+
+/// Resample a glyph `phase / PHASES` of a pixel to the right, which makes it one pixel
+/// wider.
+fn shift(glyph: &[u8], width: usize, height: usize, phase: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(glyph.len() + height);
+    for y in 0..height {
+        let row = &glyph[y * width..(y + 1) * width];
+        for x in 0..=width {
+            let here = row.get(x).map_or(0, |&a| a as usize);
+            let left = if x > 0 { row[x - 1] as usize } else { 0 };
+            out.push(((here * (PHASES - phase) + left * phase + PHASES / 2) / PHASES) as u8);
+        }
+    }
+    out
+}
+
+/// Turn a glyph 90° clockwise, so that its top faces right.
+fn rotate(glyph: &[u8], width: usize, height: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(glyph.len());
+    for y in 0..width {
+        for x in 0..height {
+            out.push(glyph[(height - 1 - x) * width + y]);
+        }
+    }
+    out
+}
+
+/// Paint `color` over a row of BGR pixels, using a row of glyph coverage as the alpha.
+pub(crate) fn blit_row(pixels: &mut [u8], coverage: &[u8], color: BitmapPixel) {
+    for (pixel, &alpha) in pixels.chunks_exact_mut(3).zip(coverage) {
+        match alpha {
+            0 => {}
+            255 => pixel.copy_from_slice(&[color.0, color.1, color.2]),
+            _ => blend(pixel, color, alpha),
+        }
+    }
+}
+
+fn blend(pixel: &mut [u8], color: BitmapPixel, alpha: u8) {
+    pixel[0] = mix(pixel[0], color.0, alpha);
+    pixel[1] = mix(pixel[1], color.1, alpha);
+    pixel[2] = mix(pixel[2], color.2, alpha);
+}
+
+/// Mix a color channel over another with the given alpha.
+pub(crate) fn mix(background: u8, color: u8, alpha: u8) -> u8 {
+    let alpha = alpha as u32;
+    ((color as u32 * alpha + background as u32 * (255 - alpha) + 127) / 255) as u8
+}
+
+/// Perceived brightness of a BGR pixel, using the BT.601 weights.
+pub(crate) fn luma(pixel: &[u8]) -> u8 {
+    ((pixel[0] as u32 * 29 + pixel[1] as u32 * 150 + pixel[2] as u32 * 77 + 128) >> 8) as u8
+}
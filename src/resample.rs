@@ -0,0 +1,110 @@
+use alloc::{vec, vec::Vec};
+
+/// Resample an 8-bit image, averaging the area each new pixel covers when shrinking and
+/// with a Catmull-Rom bicubic filter when growing. Anything outside the image counts as
+/// zero.
+pub(crate) fn resample(src: &[u8], size: (usize, usize), new_size: (usize, usize)) -> Vec<u8> {
+    resample_plane(src, size, new_size, false)
+}
+
+/// Resample a 24-bit image one channel at a time, like [`resample`] but with the edges
+/// extending out of the image instead of fading to black.
+pub(crate) fn resample_bgr(
+    src: &[u8],
+    (width, height): (usize, usize),
+    (new_width, new_height): (usize, usize),
+) -> Vec<u8> {
+    let mut out = vec![0; new_width * new_height * 3];
+    for channel in 0..3 {
+        let plane: Vec<u8> = src[..width * height * 3]
+            .iter()
+            .skip(channel)
+            .step_by(3)
+            .copied()
+            .collect();
+        let plane = resample_plane(&plane, (width, height), (new_width, new_height), true);
+        for (out, value) in out.iter_mut().skip(channel).step_by(3).zip(plane) {
+            *out = value;
+        }
+    }
+    out
+}
+
+fn resample_plane(
+    src: &[u8],
+    (width, height): (usize, usize),
+    (new_width, new_height): (usize, usize),
+    extend: bool,
+) -> Vec<u8> {
+    let columns = weights(width, new_width, extend);
+    let rows = weights(height, new_height, extend);
+
+    let mut horizontal = vec![0.0; new_width * height];
+    for (src, out) in src
+        .chunks_exact(width.max(1))
+        .zip(horizontal.chunks_exact_mut(new_width.max(1)))
+    {
+        for (out, (start, weights)) in out.iter_mut().zip(&columns) {
+            *out = dot(weights, src[*start..].iter().map(|&a| a as f32));
+        }
+    }
+
+    let mut out = vec![0; new_width * new_height];
+    for (out, (start, weights)) in out.chunks_exact_mut(new_width.max(1)).zip(&rows) {
+        for (x, out) in out.iter_mut().enumerate() {
+            let column = horizontal[start * new_width + x..]
+                .iter()
+                .step_by(new_width);
+            *out = (dot(weights, column.copied()).clamp(0.0, 255.0) + 0.5) as u8;
+        }
+    }
+    out
+}
+
+fn dot(weights: &[f32], values: impl Iterator<Item = f32>) -> f32 {
+    weights.iter().zip(values).map(|(w, v)| w * v).sum()
+}
+
+/// For each of the `new_len` samples along an axis of `len` pixels, the first pixel it
+/// is made of and the weights of that pixel and the following ones. With `extend`, the
+/// weights of samples near the edges are scaled up to make up for the pixels past them.
+fn weights(len: usize, new_len: usize, extend: bool) -> Vec<(usize, Vec<f32>)> {
+    let scale = new_len as f32 / len as f32;
+    (0..new_len)
+        .map(|i| {
+            // Position of the sample in the source, where pixel `j` spans `j..j + 1`.
+            let center = (i as f32 + 0.5) / scale;
+            let radius = if scale < 1.0 { 0.5 / scale } else { 2.0 };
+            let first = (center - radius).max(0.0) as usize;
+            let last = ((center + radius) as usize + 1).min(len);
+
+            let mut weights: Vec<f32> = (first..last)
+                .map(|j| {
+                    let j = j as f32;
+                    if scale < 1.0 {
+                        let covered = (center + radius).min(j + 1.0) - (center - radius).max(j);
+                        covered.max(0.0) * scale
+                    } else {
+                        catmull_rom(j + 0.5 - center)
+                    }
+                })
+                .collect();
+            let total: f32 = weights.iter().sum();
+            if extend && total > 0.0 {
+                weights.iter_mut().for_each(|w| *w /= total);
+            }
+            (first, weights)
+        })
+        .collect()
+}
+
+fn catmull_rom(x: f32) -> f32 {
+    let x = x.abs();
+    if x < 1.0 {
+        (1.5 * x - 2.5) * x * x + 1.0
+    } else if x < 2.0 {
+        ((-0.5 * x + 2.5) * x - 4.0) * x + 2.0
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Error;
+use alloc::vec::Vec;
+
+/// A destination for images streamed by [`write_text_to`](crate::write_text_to).
+///
+/// This is a minimal `no_std` take on `std::io::Write`. It is implemented for `Vec<u8>`
+/// and for closures taking each chunk of the image, which can forward them to a file,
+/// a socket or the host of a WASM module.
+pub trait Write {
+    /// Write the whole buffer. Implementations that can fail should return
+    /// [`Error::Write`].
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error>;
+}
+
+impl Write for Vec<u8> {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&[u8]) -> Result<(), Error>> Write for F {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        self(buf)
+    }
+}
+
+/// Writes into a fixed-size buffer, failing if it runs out of space.
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    /// Number of bytes written so far.
+    pub len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let end = self.len + buf.len();
+        if end > self.buf.len() {
+            return Err(Error::BufferTooSmall {
+                required: end,
+                len: self.buf.len(),
+            });
+        }
+        self.buf[self.len..end].copy_from_slice(buf);
+        self.len = end;
+        Ok(())
+    }
+}
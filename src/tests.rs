@@ -1,13 +1,15 @@
 extern crate std;
 use super::*;
+use std::boxed::Box;
 use std::fs::File;
 use std::io::Write;
+use std::vec;
 
 #[test]
 fn length() {
     assert_eq!(
-        calculate_text_length("Hello World", &fonts::times::TIMES36),
-        177.0
+        layout::text_advance("Hello World", &fonts::times::TIMES36),
+        177 * fonts::SUBPIXELS
     );
 }
 
@@ -48,3 +50,2074 @@ fn pagination() {
     let mut file = File::create("test2.bmp").unwrap();
     file.write_all(&img).unwrap();
 }
+
+fn bmp_size(img: &[u8]) -> (u32, u32) {
+    (
+        u32::from_le_bytes(img[18..22].try_into().unwrap()),
+        u32::from_le_bytes(img[22..26].try_into().unwrap()),
+    )
+}
+
+/// Color of the pixel at the given position, counting from the top left corner.
+fn bmp_pixel(img: &[u8], x: usize, y: usize) -> BitmapPixel {
+    let (width, height) = bmp_size(img);
+    let stride = (width as usize * 3).div_ceil(4) * 4;
+    let offset = 54 + (height as usize - 1 - y) * stride + x * 3;
+    BitmapPixel(img[offset], img[offset + 1], img[offset + 2])
+}
+
+fn lines_of(paragraphs: &[usize]) -> Vec<layout::Line> {
+    paragraphs
+        .iter()
+        .map(|&paragraph| layout::Line::new("", 0, paragraph, false))
+        .collect()
+}
+
+#[test]
+fn height_pagination() {
+    let options = ImageOptions {
+        page_height: Some(600.0),
+        paragraph_spacing: 10.0,
+        ..Default::default()
+    };
+    let text = include_str!("./lorem.txt");
+    let pages = page_count(text, &fonts::times::TIMES24, &options);
+    assert!(pages > 1);
+    for page in 1..=pages {
+        let img = write_text(text, page, fonts::times::TIMES24, options);
+        assert!(bmp_size(&img).1 <= 600);
+    }
+}
+
+#[test]
+fn widows_and_orphans() {
+    let font = fonts::times::TIMES18;
+    let options = ImageOptions {
+        page_height: Some(font.height * 4.0),
+        padding: Padding(0.0, 0.0).into(),
+        ..Default::default()
+    };
+
+    // A lone line of the second paragraph is moved to the next page.
+    let lines = lines_of(&[0, 0, 0, 1, 1, 1]);
+    assert_eq!(
+        layout::paginate(&lines, &layout::Metrics::new(&font, &options)),
+        [0..3, 3..6]
+    );
+
+    // The last line of the first paragraph is not left alone on the next page.
+    let lines = lines_of(&[0, 0, 0, 0, 0, 1]);
+    assert_eq!(
+        layout::paginate(&lines, &layout::Metrics::new(&font, &options)),
+        [0..3, 3..6]
+    );
+
+    // Without control, pages are filled completely.
+    let options = ImageOptions {
+        orphans: 1,
+        widows: 1,
+        ..options
+    };
+    assert_eq!(
+        layout::paginate(&lines, &layout::Metrics::new(&font, &options)),
+        [0..4, 4..6]
+    );
+}
+
+#[test]
+fn invalid_options() {
+    let font = fonts::times::TIMES18;
+    let write = |options: ImageOptions| try_write_text("Hello World", 1, font, options);
+
+    assert_eq!(
+        write(ImageOptions {
+            width: 0.0,
+            ..Default::default()
+        }),
+        Err(Error::InvalidWidth(0.0))
+    );
+    assert_eq!(
+        write(ImageOptions {
+            width: 30.0,
+            ..Default::default()
+        }),
+        Err(Error::PaddingTooLarge {
+            padding: 40.0,
+            width: 30.0
+        })
+    );
+    let padding = Margins::new(0.0, 1.0, f32::NAN, 0.0);
+    assert!(matches!(
+        write(ImageOptions {
+            padding,
+            ..Default::default()
+        }),
+        Err(Error::InvalidPadding(Margins { right: 1.0, .. }))
+    ));
+    assert_eq!(
+        write(ImageOptions {
+            lines: 0,
+            ..Default::default()
+        }),
+        Err(Error::ZeroLines)
+    );
+    assert!(matches!(
+        write(ImageOptions {
+            page_height: Some(50.0),
+            ..Default::default()
+        }),
+        Err(Error::PageTooShort { .. })
+    ));
+}
+
+#[test]
+fn invalid_text() {
+    let font = fonts::times::TIMES18;
+    let options = ImageOptions::default();
+
+    assert_eq!(try_write_text("", 0, font, options), Err(Error::EmptyText));
+    assert_eq!(
+        try_write_text("caf\u{2603}", 0, font, options),
+        Err(Error::UnsupportedCharacter('\u{2603}'))
+    );
+    assert_eq!(
+        try_write_text("Hello World", 2, font, options),
+        Err(Error::PageOutOfRange { page: 2, pages: 1 })
+    );
+    assert_eq!(try_page_count("Hello World", &font, &options), Ok(1));
+
+    // The infallible version still filters out what it can't render.
+    assert_eq!(
+        write_text("caf\u{2603}e", 0, font, options),
+        write_text("cafe", 0, font, options)
+    );
+}
+
+#[test]
+fn fractional_padding() {
+    let font = fonts::times::TIMES18;
+    let img = try_write_text(
+        "H",
+        0,
+        font,
+        ImageOptions {
+            padding: Padding(10.5, 10.5).into(),
+            width: 100.5,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(bmp_size(&img), (101, 21 + 11 * 2));
+
+    let glyph = font.glyph('H');
+    let width = font.glyph_width('H');
+    for y in 0..21 {
+        for x in 0..width {
+            let expected = if glyph[y * width + x] != 0 {
+                BitmapPixel(0, 0, 0)
+            } else {
+                BitmapPixel(255, 255, 255)
+            };
+            assert_eq!(bmp_pixel(&img, 11 + x, 11 + y), expected);
+        }
+    }
+}
+
+#[test]
+fn odd_widths() {
+    let font = fonts::times::TIMES18;
+    let sizes = [
+        (799.5, true, 800),
+        (801.0, true, 801),
+        (800.0, false, 93 + 2 * 2),
+    ];
+    for (width, constant_width, expected) in sizes {
+        let img = write_text(
+            "Hello World.",
+            0,
+            font,
+            ImageOptions {
+                width,
+                constant_width,
+                padding: Padding(1.5, 0.0).into(),
+                ..Default::default()
+            },
+        );
+        let (w, h) = bmp_size(&img);
+        assert_eq!(w as usize, expected);
+        assert_eq!(img.len(), 54 + (expected * 3).div_ceil(4) * 4 * h as usize);
+    }
+}
+
+#[test]
+fn write_into_buffer() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::georgia::GEORGIA24;
+    let options = ImageOptions {
+        width: 601.0,
+        lines: 10,
+        ..Default::default()
+    };
+    let expected = write_text(text, 2, font, options);
+
+    let size = required_size(text, 2, font, options).unwrap();
+    assert_eq!(size, expected.len());
+
+    // Leftover data in the buffer must be overwritten, padding included.
+    let mut buf = vec![0xAA; size + 10];
+    assert_eq!(write_text_into(text, 2, font, options, &mut buf), Ok(size));
+    assert_eq!(buf[..size], expected[..]);
+    assert_eq!(
+        write_text_into(text, 2, font, options, &mut buf[..size - 1]),
+        Err(Error::BufferTooSmall {
+            required: size,
+            len: size - 1
+        })
+    );
+}
+
+#[test]
+fn streaming() {
+    let font = fonts::times::TIMES18;
+    let options = ImageOptions::default();
+    let text = include_str!("./lorem.txt").repeat(72);
+    let size = required_size(&text, 0, font, options).unwrap();
+    let expected = try_write_text(&text, 0, font, options).unwrap();
+    assert!(bmp_size(&expected).1 >= 50_000);
+
+    let mut img = Vec::new();
+    let mut largest = 0;
+    let mut sink = |chunk: &[u8]| {
+        largest = largest.max(chunk.len());
+        img.extend_from_slice(chunk);
+        Ok(())
+    };
+    write_text_to(&text, 0, font, options, &mut sink).unwrap();
+    assert_eq!(img.len(), size);
+    assert!(img == expected);
+    // Only a band of rows is ever held at once.
+    assert!(largest <= 2400 * 64);
+
+    let mut failing = |_: &[u8]| Err(Error::Write);
+    assert_eq!(
+        write_text_to("Hello World", 0, font, options, &mut failing),
+        Err(Error::Write)
+    );
+}
+
+/// Decode a BMP file written by the library, returning its size and pixels from the
+/// top left corner.
+fn decode_bmp(img: &[u8]) -> (usize, usize, Vec<BitmapPixel>) {
+    let u16_at = |at: usize| u16::from_le_bytes(img[at..at + 2].try_into().unwrap());
+    let u32_at = |at: usize| u32::from_le_bytes(img[at..at + 4].try_into().unwrap());
+
+    assert_eq!(&img[..2], b"BM");
+    assert_eq!(u32_at(2) as usize, img.len());
+    let offset = u32_at(10) as usize;
+    let info_size = u32_at(14) as usize;
+    let width = u32_at(18) as i32 as usize;
+    let height = u32_at(22) as i32;
+    let bits = u16_at(28) as usize;
+    let colors = u32_at(46) as usize;
+    let palette = &img[14 + info_size..14 + info_size + colors * 4];
+
+    let stride = (width * bits).div_ceil(32) * 4;
+    assert_eq!(u32_at(34) as usize, stride * height.unsigned_abs() as usize);
+    assert_eq!(offset + u32_at(34) as usize, img.len());
+
+    let mut pixels = Vec::new();
+    for y in 0..height.unsigned_abs() as usize {
+        let row = if height < 0 {
+            y
+        } else {
+            height as usize - 1 - y
+        };
+        let row = &img[offset + row * stride..offset + (row + 1) * stride];
+        assert!(row[(width * bits).div_ceil(8)..].iter().all(|&b| b == 0));
+        for x in 0..width {
+            pixels.push(match bits {
+                24 | 32 => {
+                    let p = &row[x * bits / 8..];
+                    BitmapPixel(p[0], p[1], p[2])
+                }
+                _ => {
+                    let index = (row[x * bits / 8] >> (8 - bits - x * bits % 8)) as usize
+                        & ((1 << bits) - 1);
+                    let p = &palette[index * 4..];
+                    BitmapPixel(p[0], p[1], p[2])
+                }
+            });
+        }
+    }
+    (width, height.unsigned_abs() as usize, pixels)
+}
+
+#[test]
+fn bmp_round_trip() {
+    let font = fonts::palatino::PALATINO18;
+    let text = "The quick brown fox jumps over the lazy dog.";
+    let options = ImageOptions {
+        width: 333.0,
+        text_color: 0x1F1E33,
+        background_color: 0xFAF0E6,
+        ..Default::default()
+    };
+    let expected = decode_bmp(&write_text(text, 0, font, options));
+
+    let with_bmp = |bmp_options: bmp::BmpOptions| {
+        let options = ImageOptions {
+            format: ImageFormat::Bmp(bmp_options),
+            ..options
+        };
+        let img = try_write_text(text, 0, font, options).unwrap();
+        assert_eq!(img.len(), required_size(text, 0, font, options).unwrap());
+        img
+    };
+
+    for pixel_format in [bmp::PixelFormat::Bgr24, bmp::PixelFormat::Bgra32] {
+        for top_down in [false, true] {
+            let img = with_bmp(bmp::BmpOptions {
+                pixel_format,
+                top_down,
+                dpi: 300,
+            });
+            // 300 DPI is 11811 pixels per meter.
+            assert_eq!(img[38..46], [0x23, 0x2E, 0, 0, 0x23, 0x2E, 0, 0]);
+            assert_eq!(decode_bmp(&img), expected);
+        }
+    }
+
+    let img = with_bmp(bmp::BmpOptions {
+        pixel_format: bmp::PixelFormat::Gray8,
+        ..Default::default()
+    });
+    let (width, height, pixels) = decode_bmp(&img);
+    assert_eq!((width, height), (expected.0, expected.1));
+    for (gray, color) in pixels.iter().zip(&expected.2) {
+        let luma = (color.0 as u32 * 29 + color.1 as u32 * 150 + color.2 as u32 * 77 + 128) >> 8;
+        assert_eq!(*gray, BitmapPixel(luma as u8, luma as u8, luma as u8));
+    }
+}
+
+#[test]
+fn bmp_row_stride() {
+    let font = fonts::times::TIMES18;
+    for width in 1..=9 {
+        for pixel_format in [
+            bmp::PixelFormat::Bgr24,
+            bmp::PixelFormat::Bgra32,
+            bmp::PixelFormat::Gray8,
+            bmp::PixelFormat::Indexed8,
+            bmp::PixelFormat::Indexed4,
+            bmp::PixelFormat::Indexed1,
+        ] {
+            let options = ImageOptions {
+                width: width as f32,
+                padding: Padding(0.0, 0.0).into(),
+                format: ImageFormat::Bmp(bmp::BmpOptions {
+                    pixel_format,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            let (w, h, _) = decode_bmp(&write_text("Hello", 0, font, options));
+            assert_eq!((w, h), (width, 21));
+        }
+    }
+}
+
+#[test]
+fn bmp_palettes() {
+    let text = include_str!("./lorem.txt");
+    // Scaled glyphs have smooth edges, so there are shades between the two colors.
+    let font = fonts::atkinson::ATKINSON24.scaled(27.0);
+    let options = ImageOptions {
+        text_color: 0x203040,
+        background_color: 0xF0E0D0,
+        ..Default::default()
+    };
+    let full = write_text(text, 1, font, options);
+    let expected = decode_bmp(&full);
+    let mut shades = expected.2.clone();
+    shades.sort_by_key(|p| (p.0, p.1, p.2));
+    shades.dedup();
+    assert!(shades.len() > 100);
+
+    let bmp = |pixel_format| {
+        ImageFormat::Bmp(bmp::BmpOptions {
+            pixel_format,
+            ..Default::default()
+        })
+    };
+    let distance = |a: &BitmapPixel, b: &BitmapPixel| {
+        (a.0.abs_diff(b.0))
+            .max(a.1.abs_diff(b.1))
+            .max(a.2.abs_diff(b.2))
+    };
+    for (pixel_format, ratio, error) in [
+        (bmp::PixelFormat::Indexed8, 3, 1),
+        (bmp::PixelFormat::Indexed4, 6, 9),
+        (bmp::PixelFormat::Indexed1, 24, 104),
+    ] {
+        let options = ImageOptions {
+            format: bmp(pixel_format),
+            ..options
+        };
+        let img = write_text(text, 1, font, options);
+        let (width, height, pixels) = decode_bmp(&img);
+        assert_eq!((width, height), (expected.0, expected.1));
+        let worst = pixels
+            .iter()
+            .zip(&expected.2)
+            .map(|(a, b)| distance(a, b))
+            .max();
+        assert!(worst <= Some(error));
+        // Headers and palettes aside, the size goes down by the expected ratio.
+        assert!(img.len() < full.len() / ratio + 1200);
+    }
+
+    // A frame adds colors of its own, which get a palette of the page's exact colors
+    // while there are few enough, so they are kept as they are.
+    let framed = ImageOptions {
+        frame: Some(Frame {
+            fill: Some(0x3366CC),
+            ..Frame::border(3.0, 0xCC3300)
+        }),
+        ..Default::default()
+    };
+    let short = "Framed.";
+    let expected = decode_bmp(&write_text(short, 1, fonts::times::TIMES18, framed));
+    let img = write_text(
+        short,
+        1,
+        fonts::times::TIMES18,
+        ImageOptions {
+            format: bmp(bmp::PixelFormat::Indexed8),
+            ..framed
+        },
+    );
+    assert_eq!(img[28], 8);
+    assert_eq!(decode_bmp(&img), expected);
+    assert!(expected.2.contains(&BitmapPixel(0xCC, 0x66, 0x33)));
+
+    // Illustrations keep their colors too.
+    let red = [0, 0, 255].repeat(16);
+    let illustrated = ImageOptions {
+        width: 100.0,
+        illustrations: &[Illustration {
+            pixels: &red,
+            width: 4,
+            height: 4,
+            paragraph: 1,
+        }],
+        ..Default::default()
+    };
+    let text = "Before.\nAfter.";
+    let pictured = decode_bmp(&write_text(text, 1, fonts::times::TIMES18, illustrated));
+    let img = write_text(
+        text,
+        1,
+        fonts::times::TIMES18,
+        ImageOptions {
+            format: bmp(bmp::PixelFormat::Indexed8),
+            ..illustrated
+        },
+    );
+    assert_eq!(img[28], 8);
+    assert_eq!(decode_bmp(&img), pictured);
+    assert_eq!(
+        pictured.2[(20 + 21 + 30) * 100 + 50],
+        BitmapPixel(0, 0, 255)
+    );
+
+    // As do highlights behind the text.
+    let spans = [Span {
+        range: 0..6,
+        decoration: Decoration::Highlight(0x00FFFF),
+    }];
+    let highlighted = ImageOptions {
+        spans: &spans,
+        ..illustrated
+    };
+    let marked = decode_bmp(&write_text(text, 1, fonts::times::TIMES18, highlighted));
+    let img = write_text(
+        text,
+        1,
+        fonts::times::TIMES18,
+        ImageOptions {
+            format: bmp(bmp::PixelFormat::Indexed4),
+            ..highlighted
+        },
+    );
+    assert_eq!(img[28], 4);
+    assert_eq!(decode_bmp(&img), marked);
+    assert_eq!(marked.2[20 * 100 + 20], BitmapPixel(255, 255, 0));
+
+    // Too many colors for the palette, and the page is written in 24-bit color.
+    let img = write_text(
+        short,
+        1,
+        fonts::times::TIMES18,
+        ImageOptions {
+            format: bmp(bmp::PixelFormat::Indexed1),
+            ..framed
+        },
+    );
+    assert_eq!(img[28], 24);
+    assert_eq!(decode_bmp(&img), expected);
+
+    // So is a background image with a smooth gradient.
+    let gradient: Vec<u8> = (0..=255).flat_map(|i| [i, 255 - i, 128]).collect();
+    let textured = ImageOptions {
+        background: Some(Background {
+            pixels: &gradient,
+            width: 256,
+            height: 1,
+            fit: Fit::Stretch,
+        }),
+        ..options
+    };
+    let expected = decode_bmp(&write_text(short, 1, font, textured));
+    let img = write_text(
+        short,
+        1,
+        font,
+        ImageOptions {
+            format: bmp(bmp::PixelFormat::Indexed8),
+            ..textured
+        },
+    );
+    assert_eq!(img[28], 24);
+    assert_eq!(decode_bmp(&img), expected);
+    assert_eq!(
+        required_size(
+            short,
+            1,
+            font,
+            ImageOptions {
+                format: bmp(bmp::PixelFormat::Indexed8),
+                ..textured
+            }
+        ),
+        Ok(img.len())
+    );
+
+    // The format a page falls back to can be told ahead.
+    let format = |pixel_format, font, options| {
+        let options = ImageOptions {
+            format: bmp(pixel_format),
+            ..options
+        };
+        bmp_pixel_format(short, 1, font, options)
+    };
+    use bmp::PixelFormat::{Bgr24, Indexed1, Indexed4, Indexed8};
+    let times = fonts::times::TIMES18;
+    assert_eq!(format(Indexed1, times, framed), Ok(Some(Bgr24)));
+    assert_eq!(format(Indexed4, times, framed), Ok(Some(Indexed4)));
+    assert_eq!(format(Indexed8, times, framed), Ok(Some(Indexed8)));
+    assert_eq!(format(Indexed8, font, textured), Ok(Some(Bgr24)));
+    assert_eq!(format(Indexed1, times, options), Ok(Some(Indexed1)));
+    let qoi = ImageOptions {
+        format: ImageFormat::Qoi,
+        ..options
+    };
+    assert_eq!(bmp_pixel_format(short, 1, times, qoi), Ok(None));
+}
+
+#[test]
+fn qoi_round_trip() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::atkinson::ATKINSON24;
+    let options = ImageOptions {
+        text_color: 0x203040,
+        background_color: 0xF0E0D0,
+        ..Default::default()
+    };
+    let bmp = write_text(text, 1, font, options);
+    let (width, height, expected) = decode_bmp(&bmp);
+
+    let options = ImageOptions {
+        format: ImageFormat::Qoi,
+        ..options
+    };
+    let img = write_text(text, 1, font, options);
+    assert!(img.len() <= required_size(text, 1, font, options).unwrap());
+    // Flat colors compress well.
+    assert!(img.len() < bmp.len() / 10);
+
+    let (header, pixels) = ::qoi::decode_to_vec(&img).unwrap();
+    assert_eq!((header.width, header.height), (width as u32, height as u32));
+    assert_eq!(header.channels, ::qoi::Channels::Rgb);
+    let pixels: Vec<_> = pixels
+        .chunks_exact(3)
+        .map(|p| BitmapPixel(p[2], p[1], p[0]))
+        .collect();
+    assert_eq!(pixels, expected);
+
+    // Runs of all lengths and colors with small and large differences exercise every
+    // kind of chunk.
+    let mut encoder = qoi::Encoder::new();
+    let mut out = Vec::new();
+    let mut expected = Vec::new();
+    for i in 0..2000u32 {
+        let pixel = [
+            (i * 7 % 256) as u8,
+            (i / 3 % 256) as u8,
+            (i % 5) as u8,
+            0xFF,
+        ];
+        for _ in 0..(i % 70 + 1) {
+            encoder.push(pixel, &mut out);
+            expected.extend_from_slice(&pixel[..3]);
+        }
+    }
+    let pixels = expected.len() / 3;
+    let mut img = Vec::new();
+    img.extend_from_slice(b"qoif");
+    img.extend_from_slice(&(pixels as u32).to_be_bytes());
+    img.extend_from_slice(&1u32.to_be_bytes());
+    img.extend_from_slice(&[3, 0]);
+    encoder.finish(&mut out);
+    img.extend_from_slice(&out);
+    img.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(::qoi::decode_to_vec(&img).unwrap().1, expected);
+}
+
+/// Parse a binary PPM or PGM file into its magic number, size and pixel data.
+fn decode_pnm(img: &[u8]) -> (&str, usize, usize, &[u8]) {
+    let mut fields = img.splitn(5, |&b| b == b'\n' || b == b' ');
+    let mut next = || std::str::from_utf8(fields.next().unwrap()).unwrap();
+    let magic = next();
+    let width = next().parse().unwrap();
+    let height = next().parse().unwrap();
+    assert_eq!(next(), "255");
+    let data = fields.next().unwrap();
+    (magic, width, height, data)
+}
+
+#[test]
+fn pnm_round_trip() {
+    let text = "The quick brown fox jumps over the lazy dog.";
+    let font = fonts::atkinson::ATKINSON24;
+    let options = ImageOptions {
+        text_color: 0x203040,
+        background_color: 0xF0E0D0,
+        constant_width: false,
+        ..Default::default()
+    };
+    let (width, height, expected) = decode_bmp(&write_text(text, 0, font, options));
+
+    let options = ImageOptions {
+        format: ImageFormat::Ppm,
+        ..options
+    };
+    let img = write_text(text, 0, font, options);
+    assert_eq!(img.len(), required_size(text, 0, font, options).unwrap());
+    let (magic, w, h, data) = decode_pnm(&img);
+    assert_eq!((magic, w, h), ("P6", width, height));
+    let pixels: Vec<_> = data
+        .chunks_exact(3)
+        .map(|p| BitmapPixel(p[2], p[1], p[0]))
+        .collect();
+    assert_eq!(pixels, expected);
+
+    let options = ImageOptions {
+        format: ImageFormat::Pgm,
+        ..options
+    };
+    let img = write_text(text, 0, font, options);
+    assert_eq!(img.len(), required_size(text, 0, font, options).unwrap());
+    let (magic, w, h, data) = decode_pnm(&img);
+    assert_eq!((magic, w, h), ("P5", width, height));
+    let gray: Vec<_> = expected
+        .iter()
+        .map(|p| raster::luma(&[p.0, p.1, p.2]))
+        .collect();
+    assert_eq!(data, gray);
+}
+
+/// Decode a WebP file into its size and pixels.
+fn decode_webp(img: &[u8]) -> (usize, usize, Vec<BitmapPixel>) {
+    let mut decoder = image_webp::WebPDecoder::new(std::io::Cursor::new(img)).unwrap();
+    assert!(!decoder.is_lossy());
+    assert!(!decoder.has_alpha());
+    let (width, height) = decoder.dimensions();
+    let mut buf = vec![0; decoder.output_buffer_size().unwrap()];
+    decoder.read_image(&mut buf).unwrap();
+    let pixels = buf
+        .chunks_exact(3)
+        .map(|p| BitmapPixel(p[2], p[1], p[0]))
+        .collect();
+    (width as usize, height as usize, pixels)
+}
+
+#[test]
+fn webp_round_trip() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::atkinson::ATKINSON24;
+    let options = ImageOptions {
+        text_color: 0x203040,
+        background_color: 0xF0E0D0,
+        ..Default::default()
+    };
+    let bmp = write_text(text, 1, font, options);
+    let expected = decode_bmp(&bmp);
+
+    let options = ImageOptions {
+        format: ImageFormat::WebP,
+        ..options
+    };
+    let img = write_text(text, 1, font, options);
+    assert!(img.len() <= required_size(text, 1, font, options).unwrap());
+    assert_eq!(decode_webp(&img), expected);
+    // Much smaller than even a 1-bit bitmap.
+    assert!(img.len() < bmp.len() / 48);
+
+    // Widths that don't fill the last packed pixel of each row.
+    for width in 41..=49 {
+        let options = ImageOptions {
+            width: width as f32,
+            padding: Padding(2.0, 2.0).into(),
+            ..options
+        };
+        let img = write_text("Hi", 0, font, options);
+        let bmp = write_text(
+            "Hi",
+            0,
+            font,
+            ImageOptions {
+                format: Default::default(),
+                ..options
+            },
+        );
+        assert_eq!(decode_webp(&img), decode_bmp(&bmp));
+    }
+}
+
+#[test]
+fn webp_many_colors() {
+    // Too many colors for a palette: every pixel is stored as is.
+    let (width, height) = (300, 40);
+    let mut pixels = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            pixels.push(if (x / 7 + y / 5) % 3 == 0 {
+                webp::argb((x * 3) as u8, (y * 5) as u8, (x ^ y) as u8)
+            } else {
+                webp::argb(0x10, 0x20, 0x30)
+            });
+        }
+    }
+    let expected: Vec<_> = pixels
+        .iter()
+        .map(|&p| {
+            let [b, g, r, _] = p.to_le_bytes();
+            BitmapPixel(b, g, r)
+        })
+        .collect();
+
+    let mut img = Vec::new();
+    webp::encode_pixels(pixels, width, height, &mut img).unwrap();
+    assert_eq!(decode_webp(&img), (width, height, expected));
+
+    // Noise, which nothing compresses, stays within the size given for the widest image.
+    let height = 4;
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    let noise: Vec<u32> = (0..(1 << 14) * height)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            webp::argb(state as u8, (state >> 8) as u8, (state >> 16) as u8)
+        })
+        .collect();
+    // The decoder wraps a width of 16384 around to zero, so the widest image is only
+    // checked for its size and header.
+    for width in [1 << 14, (1 << 14) - 1] {
+        let pixels = noise[..width * height].to_vec();
+        let mut img = Vec::new();
+        webp::encode_pixels(pixels.clone(), width, height, &mut img).unwrap();
+        assert!(img.len() <= webp::encoded_size(width, height).unwrap());
+        let header = u32::from_le_bytes(img[21..25].try_into().unwrap());
+        assert_eq!(header & 0x3FFF, width as u32 - 1);
+        if width < 1 << 14 {
+            let expected: Vec<_> = pixels
+                .iter()
+                .map(|&p| {
+                    let [b, g, r, _] = p.to_le_bytes();
+                    BitmapPixel(b, g, r)
+                })
+                .collect();
+            assert_eq!(decode_webp(&img), (width, height, expected));
+        }
+    }
+    assert!(webp::encoded_size((1 << 14) + 1, height).is_err());
+}
+
+#[test]
+fn subpixel_positions() {
+    let font = fonts::times::TIMES18;
+    let height = font.line_height();
+    let width = 2 * font.glyph_width('H') + 8;
+    let coverage = |x: usize| {
+        let mut glyphs = raster::Glyphs::new(&font);
+        glyphs.prepare("HH", x);
+        let mut data = vec![0; width * height * 3];
+        let mut canvas = raster::Canvas::new(&mut data, width, 0..height);
+        canvas.fill(BitmapPixel(255, 255, 255));
+        canvas.draw_text("HH", (x, 0), width, &glyphs, BitmapPixel(0, 0, 0));
+        data.chunks_exact(3)
+            .map(|p| 255 - p[0] as i32)
+            .collect::<Vec<_>>()
+    };
+
+    let whole = coverage(2 * fonts::SUBPIXELS);
+    assert!(whole.iter().all(|&c| c == 0 || c == 255));
+    // Close enough to a whole pixel is drawn there.
+    assert_eq!(
+        coverage(3 * fonts::SUBPIXELS - 4),
+        coverage(3 * fonts::SUBPIXELS)
+    );
+
+    // Half way between pixels, each pixel is covered by half of the two it straddles.
+    let half = coverage(2 * fonts::SUBPIXELS + fonts::SUBPIXELS / 2);
+    for y in 0..height {
+        for x in 1..width {
+            let i = y * width + x;
+            let expected = (whole[i - 1] + whole[i]) / 2;
+            assert!((half[i] - expected).abs() <= 1, "({x}, {y})");
+        }
+    }
+    assert_eq!(
+        half.iter().sum::<i32>() / 255,
+        whole.iter().sum::<i32>() / 255
+    );
+}
+
+#[test]
+fn resampling() {
+    #[rustfmt::skip]
+    let src = [
+        0, 40, 255, 255,
+        80, 120, 255, 255,
+        0, 0, 10, 30,
+        0, 0, 50, 70,
+    ];
+    // Halving averages each 2x2 block.
+    assert_eq!(resample::resample(&src, (4, 4), (2, 2)), [60, 255, 0, 40]);
+
+    // Growing keeps flat areas flat and the ink of a glyph roughly the same.
+    let flat = resample::resample(&[200; 256], (16, 16), (40, 40));
+    assert!(flat[20 * 40 + 10..20 * 40 + 30].iter().all(|&a| a == 200));
+    let font = fonts::times::TIMES18;
+    let (width, height) = (font.glyph_width('W'), font.bitmap_height());
+    let glyph = resample::resample(font.glyph('W'), (width, height), (width * 3, height * 3));
+    let ink = |glyph: &[u8]| glyph.iter().map(|&a| a as f32).sum::<f32>();
+    let ratio = ink(&glyph) / ink(font.glyph('W')) / 9.0;
+    assert!((0.95..1.05).contains(&ratio), "{ratio}");
+}
+
+#[test]
+fn scaled_fonts() {
+    // Sizes that exist are used as they are, others come from the closest larger one.
+    assert_eq!(Font::from_family("times", 28.0).scale, 1.0);
+    let font = Font::from_family("times", 25.0);
+    assert_eq!((font.height, font.line_height()), (28.0, 25));
+    let font = Font::from_family("atkinson", 60.0);
+    assert_eq!((font.height, font.line_height()), (45.0, 60));
+
+    // Layout and page sizes follow the scaled metrics.
+    let text = include_str!("./lorem.txt");
+    let base = fonts::atkinson::ATKINSON36;
+    let advance = layout::text_advance(text, &font) as f32;
+    let ratio = advance / layout::text_advance(text, &base) as f32;
+    assert!((ratio - 60.0 / 45.0).abs() < 0.01, "{ratio}");
+    let options = ImageOptions {
+        lines: 10,
+        padding: Padding(20.0, 30.0).into(),
+        ..Default::default()
+    };
+    let img = write_text(text, 1, font, options);
+    assert_eq!(bmp_size(&img), (800, 10 * 60 + 60));
+    let (_, _, pixels) = decode_bmp(&img);
+    assert!(pixels.iter().any(|p| p.0 != 0 && p.0 != 255));
+
+    for height in [0.0, -5.0, 0.2, f32::NAN, 45.0 * 17.0] {
+        assert!(matches!(
+            try_write_text("Hello", 0, base.scaled(height), options),
+            Err(Error::InvalidFontSize(_))
+        ));
+    }
+
+    // A fractional height rounds to a line taller than the bitmaps, which are resampled
+    // to fit it, whichever part of the page the font is used for.
+    let fractional = Font {
+        height: 21.5,
+        ..fonts::times::TIMES18
+    };
+    assert_eq!(
+        (fractional.bitmap_height(), fractional.line_height()),
+        (21, 22)
+    );
+    let head = RunningHead {
+        center: "Head",
+        ..RunningHead::new(fractional)
+    };
+    let options = ImageOptions {
+        title: Some(Title {
+            text: "Title",
+            font: fractional,
+            spacing: 0.0,
+        }),
+        header: Some(head),
+        footer: Some(head),
+        drop_cap: 2,
+        lines: 2,
+        ..Default::default()
+    };
+    let img = try_write_text(text, 1, fractional, options).unwrap();
+    // The padding grows to twice the height of each running head.
+    assert_eq!(bmp_size(&img).1 as usize, 2 * 22 + 2 * 44 + 22);
+    let vertical = ImageOptions {
+        writing_mode: WritingMode::VerticalRl,
+        page_height: Some(200.0),
+        ..Default::default()
+    };
+    assert!(try_write_text(text, 1, fractional, vertical).is_ok());
+    for height in [0.5, f32::INFINITY] {
+        let font = Font {
+            height,
+            ..fonts::times::TIMES18
+        };
+        assert!(matches!(
+            try_write_text("Hello", 0, font.scaled(10.0), Default::default()),
+            Err(Error::InvalidFontSize(_))
+        ));
+    }
+}
+
+#[test]
+fn vertical_text() {
+    // A solid square for a CJK character and an opening bracket with only its top row
+    // inked, which ends up on the right once the bracket is turned sideways.
+    let size = 21 * 21;
+    let solid = Box::leak(vec![255; size].into_boxed_slice());
+    let mut bracket = vec![0; size];
+    bracket[..21].fill(255);
+    let bracket = Box::leak(bracket.into_boxed_slice());
+    // A comma inked in the bottom left quarter of its cell, where it sits in horizontal
+    // text.
+    let mut comma = vec![0; size];
+    for row in comma[11 * 21..].chunks_exact_mut(21) {
+        row[..10].fill(255);
+    }
+    let comma = Box::leak(comma.into_boxed_slice());
+    let extra = [('、', &comma[..]), ('「', &bracket[..]), ('あ', &solid[..])];
+    let extra = Box::leak(extra.to_vec().into_boxed_slice());
+    let font = Font {
+        extra,
+        ..fonts::times::TIMES18
+    };
+    assert_eq!(font.line_height(), 21);
+
+    let options = ImageOptions {
+        width: 200.0,
+        page_height: Some(3.0 * 21.0 + 20.0),
+        padding: Padding(10.0, 10.0).into(),
+        constant_width: false,
+        writing_mode: WritingMode::VerticalRl,
+        ..Default::default()
+    };
+    let is_ink = |img: &[u8], x, y| bmp_pixel(img, x, y) == BitmapPixel(0, 0, 0);
+
+    // Columns are three characters long and stacked from the right edge.
+    let img = write_text("ああああ「あ", 1, font, options);
+    assert_eq!(bmp_size(&img), (2 * 21 + 20, 3 * 21 + 20));
+    for y in [10, 30, 52, 72] {
+        assert!(is_ink(&img, 31, y) && is_ink(&img, 51, y));
+    }
+    assert!(is_ink(&img, 10, 10) && is_ink(&img, 30, 72));
+    assert!(!is_ink(&img, 31, 73) && !is_ink(&img, 9, 10));
+    // The bracket is turned clockwise.
+    for y in 31..52 {
+        assert!(is_ink(&img, 30, y) && !is_ink(&img, 29, y) && !is_ink(&img, 10, y));
+    }
+
+    let img = write_text(
+        "ああ",
+        1,
+        font,
+        ImageOptions {
+            constant_width: true,
+            ..options
+        },
+    );
+    assert_eq!(bmp_size(&img), (200, 83));
+    assert!(is_ink(&img, 169, 10) && is_ink(&img, 189, 51));
+    assert!(!is_ink(&img, 168, 10) && !is_ink(&img, 190, 10));
+
+    // The comma moves up to the top right quarter of its cell, below the character
+    // before it.
+    let img = write_text("あ、", 1, font, options);
+    assert_eq!(bmp_size(&img), (21 + 20, 3 * 21 + 20));
+    for y in 10..31 {
+        assert!(is_ink(&img, 10, y) && is_ink(&img, 30, y));
+    }
+    for (x, y) in [(20, 32), (29, 41)] {
+        assert!(is_ink(&img, x, y));
+    }
+    for (x, y) in [(19, 35), (30, 35), (25, 31), (25, 42), (10, 47), (15, 51)] {
+        assert!(!is_ink(&img, x, y));
+    }
+
+    // Eight columns fit across a page, page 0 holds them all.
+    let text = "あ".repeat(30);
+    assert_eq!(page_count(&text, &font, &options), 2);
+    assert_eq!(
+        bmp_size(&write_text(&text, 2, font, options)).0,
+        2 * 21 + 20
+    );
+    assert_eq!(
+        bmp_size(&write_text(&text, 0, font, options)).0,
+        10 * 21 + 20
+    );
+
+    assert!(matches!(
+        try_write_text(
+            "あ",
+            1,
+            font,
+            ImageOptions {
+                page_height: None,
+                ..options
+            }
+        ),
+        Err(Error::NoPageHeight)
+    ));
+}
+
+#[test]
+fn bidi_reordering() {
+    assert!(bidi::is_rtl("123 אבג"));
+    assert!(!bidi::is_rtl("abc אבג"));
+    assert!(!bidi::is_rtl("123"));
+
+    // Right to left runs are reversed, numbers and left to right runs inside them are not,
+    // and brackets are mirrored.
+    assert_eq!(bidi::reorder("hello (world)", false), "hello (world)");
+    assert_eq!(bidi::reorder("abc אבג def", false), "abc גבא def");
+    assert_eq!(bidi::reorder("אבג 123 דה", true), "הד 123 גבא");
+    assert_eq!(bidi::reorder("אב (ג)", true), "(ג) בא");
+    assert_eq!(bidi::reorder("אב abc de!", true), "!abc de בא");
+    assert_eq!(bidi::reorder("ع 12", true), "12 ع");
+}
+
+#[test]
+fn rtl_text() {
+    let solid = Box::leak(vec![255; 21 * 21].into_boxed_slice());
+    let extra = Box::leak(vec![('א', &solid[..])].into_boxed_slice());
+    let font = Font {
+        extra,
+        ..fonts::times::TIMES18
+    };
+
+    // Lines are broken in logical order.
+    let split = break_apart("אא אא", 50.0, &font);
+    assert_eq!(split.split, ["אא", "אא"]);
+
+    // Right to left paragraphs are aligned to the right edge.
+    let options = ImageOptions {
+        width: 200.0,
+        padding: Padding(10.0, 10.0).into(),
+        ..Default::default()
+    };
+    let img = write_text("אא\nHello", 0, font, options);
+    let is_ink = |x, y| bmp_pixel(&img, x, y) == BitmapPixel(0, 0, 0);
+    assert!(is_ink(148, 15) && is_ink(189, 15));
+    assert!(!is_ink(147, 15) && !is_ink(190, 15) && !is_ink(100, 15));
+    assert!(bmp_pixel(&img, 190, 21 + 15).0 == 255);
+}
+
+#[test]
+fn line_breaking() {
+    let breaks = linebreak::opportunities;
+    assert_eq!(breaks("Hello World"), [6]);
+    assert_eq!(breaks("Hello   World"), [8]);
+    assert_eq!(breaks("well-known and/or"), [5, 11, 15]);
+    assert_eq!(breaks("word—word"), [4, 7]);
+    assert_eq!(
+        breaks("-5 $5 5% (a b) \"c d\" 3.14 e!"),
+        [3, 6, 9, 12, 15, 18, 21, 26]
+    );
+    assert_eq!(breaks("a\u{a0}b c\u{2060}d e\u{200b}f"), [5, 11, 15]);
+    assert_eq!(breaks("e\u{301} e\u{301}"), [4]);
+
+    // Ideographs break anywhere, except before closing punctuation and small kana or
+    // after opening punctuation.
+    assert_eq!(breaks("日本語。です"), [3, 6, 12, 15]);
+    assert_eq!(breaks("ちょっと"), [9]);
+    assert_eq!(breaks("「あ」い"), [9]);
+
+    let font = fonts::times::TIMES18;
+    let width = layout::text_advance("this/", &font) as f32 / fonts::SUBPIXELS as f32;
+    let split = break_apart("this/that", width, &font).split;
+    assert_eq!(split, ["this/", "that"]);
+
+    let solid = Box::leak(vec![255; 21 * 21].into_boxed_slice());
+    let mut extra: Vec<_> = "日本語。です".chars().map(|c| (c, &solid[..])).collect();
+    extra.sort_by_key(|&(c, _)| c);
+    let font = Font {
+        extra: Box::leak(extra.into_boxed_slice()),
+        ..fonts::times::TIMES18
+    };
+    let split = break_apart("日本語。です", 50.0, &font).split;
+    assert_eq!(split, ["日本", "語。", "です"]);
+}
+
+#[test]
+fn smart_typography() {
+    use typography::{smarten, Language};
+
+    let blank = Box::leak(vec![0; 21 * 5].into_boxed_slice());
+    let mut extra: Vec<_> = "’…–—«»“”‘„‚\u{a0}\u{202f}"
+        .chars()
+        .map(|c| (c, &blank[..]))
+        .collect();
+    extra.sort_by_key(|&(c, _)| c);
+    let font = Font {
+        extra: Box::leak(extra.into_boxed_slice()),
+        ..fonts::times::TIMES18
+    };
+
+    assert_eq!(
+        smarten(
+            "\"Wait...\" she said -- 'it's 1990--2000 - or (\"not\")?'",
+            Language::English,
+            &font
+        ),
+        "“Wait…” she said — ‘it’s 1990–2000 – or (“not”)?’"
+    );
+    assert_eq!(
+        smarten("\"Ja\", sagte er --- 'nein'.", Language::German, &font),
+        "„Ja“, sagte er — ‚nein‘."
+    );
+    assert_eq!(
+        smarten(
+            "\" Bonjour \" : quoi ?! Oui; voir http://a.fr à 10:30.\n\"Non!\"",
+            Language::French,
+            &font
+        ),
+        "«\u{a0}Bonjour\u{a0}»\u{a0}: quoi\u{202f}?! Oui\u{202f}; voir http://a.fr à 10:30.\n\
+         «\u{a0}Non\u{202f}!\u{a0}»"
+    );
+
+    // Without a no-break space, guillemets keep the spaces they had.
+    let mut extra: Vec<_> = "«»".chars().map(|c| (c, &blank[..])).collect();
+    extra.sort_by_key(|&(c, _)| c);
+    let guillemets = Font {
+        extra: Box::leak(extra.into_boxed_slice()),
+        ..fonts::times::TIMES18
+    };
+    assert_eq!(
+        smarten("\" mot \"", Language::French, &guillemets),
+        "« mot »"
+    );
+
+    // Nothing changes without the glyphs.
+    let text = "\"Wait...\" -- 'it's' : ?";
+    assert_eq!(
+        smarten(text, Language::French, &fonts::times::TIMES18),
+        text
+    );
+
+    // The option applies the same conversion before laying out the text.
+    let options = ImageOptions {
+        typography: Some(Language::English),
+        ..Default::default()
+    };
+    let text = "\"Wait...\"";
+    assert_eq!(
+        write_text(text, 0, font, options),
+        write_text(
+            smarten(text, Language::English, &font),
+            0,
+            font,
+            ImageOptions::default()
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "transliterate")]
+fn transliteration() {
+    let font = fonts::times::TIMES18;
+    assert_eq!(filter_text("café — naïve", &font), "cafe - naive");
+    assert_eq!(filter_text("Łódź, Straße…", &font), "Lodz, Strasse...");
+    assert_eq!(
+        filter_text("Привет, мир! Αθήνα", &font),
+        "Privet, mir! Athina"
+    );
+    // Characters without a transliteration are dropped, whatever their low byte is.
+    assert_eq!(filter_text("a\u{4E41}b\u{1F600}c", &font), "abc");
+
+    // Glyphs the font has are kept.
+    let glyph = Box::leak(vec![0; 21 * 5].into_boxed_slice());
+    let accented = Font {
+        extra: Box::leak(vec![('é', &glyph[..])].into_boxed_slice()),
+        ..font
+    };
+    assert_eq!(filter_text("café naïve", &accented), "café naive");
+
+    let options = ImageOptions::default();
+    assert_eq!(
+        try_write_text("Ça va — très bien", 0, font, options).unwrap(),
+        write_text("Ca va - tres bien", 0, font, options)
+    );
+    assert_eq!(
+        try_write_text("Ça va \u{4E41}", 0, font, options),
+        Err(Error::UnsupportedCharacter('\u{4E41}'))
+    );
+}
+
+#[test]
+#[cfg(not(feature = "transliterate"))]
+fn no_transliteration() {
+    // Without the feature, characters the font has no glyph for are dropped as they are.
+    let font = fonts::times::TIMES18;
+    assert_eq!(filter_text("café — naïve", &font), "caf  nave");
+    assert_eq!(filter_text("a\u{4E41}b\u{1F600}c", &font), "abc");
+
+    let options = ImageOptions::default();
+    assert_eq!(
+        try_write_text("Ça va", 0, font, options),
+        Err(Error::UnsupportedCharacter('Ç'))
+    );
+    assert_eq!(
+        write_text("Ça va — très bien", 0, font, options),
+        write_text("a va  trs bien", 0, font, options)
+    );
+}
+
+#[test]
+fn running_heads() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::times::TIMES18;
+    let options = ImageOptions {
+        page_height: Some(300.0),
+        ..Default::default()
+    };
+    let options = ImageOptions {
+        header: Some(RunningHead {
+            center: "Chapter 1",
+            right: "Page {page} / {pages}",
+            ..RunningHead::new(fonts::arial::ARIAL18)
+        }),
+        footer: Some(RunningHead {
+            progress_bar: true,
+            ..RunningHead::new(font)
+        }),
+        ..options
+    };
+    let plain = ImageOptions {
+        header: None,
+        footer: None,
+        ..options
+    };
+
+    // The header needs more than the padding, leaving room for one line less per page.
+    let metrics = layout::Metrics::new(&font, &options);
+    let line_height = font.line_height();
+    assert_eq!(metrics.padding_top, line_height * 2);
+    assert_eq!(metrics.padding_bottom, 20);
+    let lines = layout::flow(text, &font, &options).0.len();
+    let per_page = |top: usize| (300 - top - 20) / line_height;
+    assert_eq!(
+        page_count(text, &font, &plain),
+        lines.div_ceil(per_page(20))
+    );
+    assert_eq!(
+        page_count(text, &font, &options),
+        lines.div_ceil(per_page(42))
+    );
+    let pages = page_count(text, &font, &options);
+
+    let rows_with_ink =
+        |img: &[u8], rows: core::ops::Range<usize>, columns: core::ops::Range<usize>| {
+            rows.filter(|&y| columns.clone().any(|x| bmp_pixel(img, x, y).0 < 128))
+                .count()
+        };
+    let img = write_text(text, 2, font, options);
+    let (_, height) = bmp_size(&img);
+    assert!(rows_with_ink(&img, 0..42, 300..500) > 5);
+    assert!(rows_with_ink(&img, 0..42, 600..780) > 5);
+    assert_eq!(rows_with_ink(&img, 0..42, 20..300), 0);
+    assert_eq!(rows_with_ink(&img, 0..42, 780..800), 0);
+
+    // The progress bar is filled up to how far the page is through the text.
+    let bar = height as usize - 9;
+    let filled = 20 + (760.0 * 2.0 / pages as f32 + 0.5) as usize;
+    assert_eq!(bmp_pixel(&img, 20, bar), BitmapPixel(0, 0, 0));
+    assert_eq!(bmp_pixel(&img, filled - 1, bar), BitmapPixel(0, 0, 0));
+    assert_eq!(bmp_pixel(&img, filled, bar), BitmapPixel(207, 207, 207));
+    assert_eq!(bmp_pixel(&img, 779, bar), BitmapPixel(207, 207, 207));
+    assert_eq!(bmp_pixel(&img, 780, bar), BitmapPixel(255, 255, 255));
+
+    // Page 0 has no running heads.
+    let img = write_text(text, 0, font, options);
+    assert_eq!(rows_with_ink(&img, 0..42, 0..800), 0);
+
+    let options = ImageOptions {
+        header: Some(RunningHead::new(font.scaled(0.0))),
+        ..options
+    };
+    assert!(matches!(
+        try_write_text(text, 1, font, options),
+        Err(Error::InvalidFontSize(_))
+    ));
+}
+
+#[test]
+fn title_and_drop_cap() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::times::TIMES18;
+    let line_height = font.line_height();
+    let options = ImageOptions {
+        page_height: Some(300.0),
+        ..Default::default()
+    };
+    let title = Title {
+        text: "Chapter One",
+        font: fonts::times::TIMES36,
+        spacing: 10.0,
+    };
+    let titled = ImageOptions {
+        title: Some(title),
+        ..options
+    };
+
+    // The title takes room from the first page only.
+    let metrics = layout::Metrics::new(&font, &titled);
+    assert_eq!(
+        metrics.title_height,
+        fonts::times::TIMES36.line_height() + 10
+    );
+    let lines = layout::flow(text, &font, &options).0;
+    let pages = layout::paginate(&lines, &metrics);
+    let untitled = layout::paginate(&lines, &layout::Metrics::new(&font, &options));
+    assert!(pages[0].len() <= (300 - 40 - metrics.title_height) / line_height);
+    assert!(pages[0].len() < untitled[0].len());
+    assert!(pages[1].len() <= (300 - 40) / line_height);
+
+    let rows_with_ink = |img: &[u8], rows: core::ops::Range<usize>| {
+        rows.filter(|&y| (0..800).any(|x| bmp_pixel(img, x, y).0 < 128))
+            .count()
+    };
+    let ink_columns = |img: &[u8], rows: core::ops::Range<usize>| {
+        let columns = (0..800).filter(|&x| rows.clone().any(|y| bmp_pixel(img, x, y).0 < 128));
+        let columns: Vec<usize> = columns.collect();
+        (columns[0], columns[columns.len() - 1])
+    };
+    let img = write_text(text, 1, font, titled);
+    let title_rows = 20..20 + fonts::times::TIMES36.line_height();
+    let (left, right) = ink_columns(&img, title_rows.clone());
+    assert!(left > 300 && right < 500);
+    assert!((left + right).abs_diff(800) < 6);
+    // The first line of text starts below the title and its spacing.
+    let text_top = 20 + metrics.title_height;
+    assert_eq!(rows_with_ink(&img, title_rows.end..text_top), 0);
+    assert!(rows_with_ink(&img, text_top..text_top + line_height) > 5);
+    // Later pages have no title, their text starts at the top.
+    let img = write_text(text, 2, font, titled);
+    assert!(rows_with_ink(&img, 20..20 + line_height) > 5);
+
+    // A drop cap narrows the lines beside it and reaches their baseline.
+    let split = break_apart("Lorem ipsum dolor sit amet", 100.0, &font).split;
+    let capped = break_apart_with_drop_cap("Lorem ipsum dolor sit amet", 100.0, &font, 2);
+    assert!(capped.split[0].starts_with("orem"));
+    assert!(capped.split.len() > split.len());
+    assert!(capped.width <= 100.0);
+
+    let dropped = ImageOptions {
+        drop_cap: 3,
+        ..options
+    };
+    let (capped, metrics) = layout::flow(text, &font, &dropped);
+    let cap = metrics.drop_cap.unwrap();
+    assert_eq!((cap.letter, cap.lines), ('L', 3));
+    assert!(capped[0].text.starts_with("orem"));
+    for line in &capped[..3] {
+        assert!(line.advance <= 760 * fonts::SUBPIXELS - cap.indent);
+    }
+    let img = write_text(text, 1, font, dropped);
+    let cap_rows = 20..20 + 3 * line_height;
+    let (left, right) = ink_columns(&img, cap_rows.clone());
+    assert!(left < 23);
+    assert!(right > 700);
+    let (_, cap_right) = ink_columns(&img, cap_rows.start + 5..cap_rows.end - 5);
+    assert!(cap_right > 700);
+    // The cap leaves a gap before the narrowed lines.
+    let gap = (left + 1..left + cap.indent / fonts::SUBPIXELS)
+        .rev()
+        .find(|&x| cap_rows.clone().any(|y| bmp_pixel(&img, x, y).0 < 128));
+    assert!(gap.is_some_and(|x| x < 20 + cap.indent / fonts::SUBPIXELS - 3));
+    // The rest of the page is laid out as usual.
+    let plain = write_text(text, 2, font, options);
+    assert_eq!(write_text(text, 2, font, dropped).len(), plain.len());
+
+    // Right to left text and text not starting with a letter have no drop cap.
+    assert_eq!(layout::drop_cap("\"Lorem", &font, 3).1, None);
+    assert_eq!(layout::drop_cap("שלום", &font, 3).1, None);
+    assert_eq!(layout::drop_cap("Lorem", &font, 1).1, None);
+
+    // A title too tall for the page is an error rather than a page past its height.
+    let long = Title {
+        text: "A title long enough to take several lines",
+        font: fonts::times::TIMES36,
+        spacing: 0.0,
+    };
+    let short = ImageOptions {
+        width: 200.0,
+        page_height: Some(100.0),
+        title: Some(long),
+        ..Default::default()
+    };
+    let title_height = layout::Metrics::new(&font, &short).title_height;
+    assert!(title_height > 100);
+    assert_eq!(
+        try_write_text(text, 1, font, short),
+        Err(Error::PageTooShort {
+            page_height: 100.0,
+            required: (line_height + 40 + title_height) as f32,
+        })
+    );
+    let roomy = ImageOptions {
+        page_height: Some((line_height + 40 + title_height) as f32),
+        ..short
+    };
+    let img = write_text(text, 1, font, roomy);
+    assert_eq!(bmp_size(&img).1 as usize, line_height + 40 + title_height);
+
+    // Without a page height, the title cannot outgrow the lines of the page either.
+    for spacing in [-1.0, f32::NAN, f32::INFINITY] {
+        let spaced = ImageOptions {
+            title: Some(Title { spacing, ..title }),
+            ..options
+        };
+        assert!(matches!(
+            try_write_text(text, 1, font, spaced),
+            Err(Error::InvalidTitleSpacing(_))
+        ));
+    }
+    let lines = ImageOptions {
+        title: Some(Title {
+            spacing: 1e6,
+            ..title
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        try_write_text(text, 1, font, lines),
+        Err(Error::TitleTooTall {
+            height: fonts::times::TIMES36.line_height() + 1_000_000,
+            max: 60 * line_height,
+        })
+    );
+    let huge = ImageOptions {
+        page_height: Some(300.0),
+        title: Some(Title {
+            spacing: 1e30,
+            ..title
+        }),
+        ..lines
+    };
+    assert!(matches!(
+        try_write_text(text, 1, font, huge),
+        Err(Error::PageTooShort { .. })
+    ));
+
+    let dropped = ImageOptions {
+        drop_cap: 20,
+        ..options
+    };
+    assert_eq!(
+        try_write_text(text, 1, font, dropped),
+        Err(Error::DropCapTooLarge(20))
+    );
+}
+
+#[test]
+fn columns() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::times::TIMES18;
+    let options = ImageOptions {
+        page_height: Some(300.0),
+        columns: 2,
+        gutter: 40.0,
+        ..Default::default()
+    };
+
+    // Each column is as wide as the text fits after the gutters, and lines are broken
+    // to it.
+    let (lines, metrics) = layout::flow(text, &font, &options);
+    assert_eq!(metrics.column_width(), (760 - 40) / 2);
+    assert_eq!(metrics.column_left(1), 20 + 360 + 40);
+    assert!(lines.iter().all(|l| l.width() <= 360));
+    assert_eq!(
+        lines,
+        layout::layout(text, 360, &font),
+        "columns break like break_apart at their width"
+    );
+
+    // Text fills a column before the next, and both before the next page.
+    let columns = layout::columns(&lines, &metrics);
+    let pages = layout::paginate(&lines, &metrics);
+    assert_eq!(pages.len(), columns.len().div_ceil(2));
+    assert_eq!(pages[0], columns[0].start..columns[1].end);
+    assert_eq!(page_count(text, &font, &options), pages.len());
+
+    let ink = |img: &[u8], columns: core::ops::Range<usize>| {
+        (20..280)
+            .filter(|&y| columns.clone().any(|x| bmp_pixel(img, x, y).0 < 128))
+            .count()
+    };
+    let img = write_text(text, 1, font, options);
+    assert_eq!(bmp_size(&img).0, 800);
+    assert!(ink(&img, 20..380) > 100);
+    assert!(ink(&img, 420..780) > 100);
+    assert_eq!(ink(&img, 381..420), 0);
+
+    // Page 0 balances all the text between the columns.
+    let img = write_text(text, 0, font, options);
+    let (_, height) = bmp_size(&img);
+    let half = lines.len().div_ceil(2);
+    assert!((height as usize) < 40 + (half + 2) * font.line_height());
+
+    let invalid = |options| try_write_text(text, 1, font, options).err();
+    let zero = ImageOptions {
+        columns: 0,
+        ..options
+    };
+    assert_eq!(invalid(zero), Some(Error::ZeroColumns));
+    let wide = ImageOptions {
+        gutter: 800.0,
+        ..options
+    };
+    assert_eq!(invalid(wide), Some(Error::InvalidGutter(800.0)));
+    let negative = ImageOptions {
+        gutter: -1.0,
+        ..options
+    };
+    assert_eq!(invalid(negative), Some(Error::InvalidGutter(-1.0)));
+}
+
+#[test]
+fn margins() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::times::TIMES18;
+    assert_eq!(
+        Margins::from(Padding(20.0, 10.0)),
+        Margins::new(10.0, 20.0, 10.0, 20.0)
+    );
+
+    // A wide inner margin on the left, as on the right-hand page of a book.
+    let options = ImageOptions {
+        padding: Margins::new(5.0, 10.0, 30.5, 60.0),
+        lines: 10,
+        ..Default::default()
+    };
+    let (lines, metrics) = layout::flow(text, &font, &options);
+    assert_eq!(metrics.text_width(), 800 - 70);
+    assert!(lines.iter().all(|l| l.width() <= 730));
+    assert!(lines.iter().any(|l| l.width() > 700));
+
+    let img = write_text(text, 1, font, options);
+    let (width, height) = bmp_size(&img);
+    assert_eq!((width, height), (800, 5 + 10 * 21 + 31));
+    let ink = |x: usize, y: usize| bmp_pixel(&img, x, y).0 < 128;
+    let columns =
+        |x: core::ops::Range<usize>| x.filter(|&x| (0..height as usize).any(|y| ink(x, y)));
+    let rows = |y: core::ops::Range<usize>| y.filter(|&y| (0..800).any(|x| ink(x, y)));
+    assert_eq!(columns(0..60).count(), 0);
+    assert_eq!(columns(790..800).count(), 0);
+    assert!(columns(60..63).count() > 0);
+    assert!(rows(5..10).count() > 0);
+    assert_eq!(rows(height as usize - 31..height as usize).count(), 0);
+
+    // Without a constant width, the page is as wide as the text and both side margins.
+    let options = ImageOptions {
+        constant_width: false,
+        ..options
+    };
+    let widest = lines.iter().map(layout::Line::width).max().unwrap();
+    let img = write_text(text, 1, font, options);
+    assert_eq!(bmp_size(&img).0 as usize, widest + 70);
+}
+
+#[test]
+fn decorations() {
+    let font = fonts::times::TIMES18;
+    let text = "The end of a scene.\n* * *\nThe start of the next.";
+    let options = ImageOptions {
+        width: 200.0,
+        lines: 3,
+        ..Default::default()
+    };
+    let plain = write_text(text, 1, font, options);
+    let (width, height) = bmp_size(&plain);
+    let (width, height) = (width as usize, height as usize);
+    let line_height = font.line_height();
+
+    // A 2x2 checkerboard of a dark and a light pixel, tiled over the page.
+    let pixels = [10, 20, 30, 200, 210, 220, 200, 210, 220, 10, 20, 30];
+    let tiled = Background {
+        pixels: &pixels,
+        width: 2,
+        height: 2,
+        fit: Fit::Tile,
+    };
+    let img = write_text(
+        text,
+        1,
+        font,
+        ImageOptions {
+            background: Some(tiled),
+            ..options
+        },
+    );
+    assert_eq!(bmp_pixel(&img, 0, 0), BitmapPixel(10, 20, 30));
+    assert_eq!(bmp_pixel(&img, 1, 0), BitmapPixel(200, 210, 220));
+    assert_eq!(bmp_pixel(&img, 199, 102), BitmapPixel(200, 210, 220));
+    assert_eq!(bmp_pixel(&img, 199, 101), BitmapPixel(10, 20, 30));
+
+    // Stretched, the left column of the image covers the left of the page.
+    let stretched = Background {
+        pixels: &[0, 0, 0, 255, 255, 255],
+        width: 2,
+        height: 1,
+        fit: Fit::Stretch,
+    };
+    let img = write_text(
+        text,
+        1,
+        font,
+        ImageOptions {
+            background: Some(stretched),
+            ..options
+        },
+    );
+    assert_eq!(bmp_pixel(&img, 0, 5), BitmapPixel(0, 0, 0));
+    assert_eq!(bmp_pixel(&img, 199, 5), BitmapPixel(255, 255, 255));
+    let middle = bmp_pixel(&img, 100, 5).0;
+    assert!((120..136).contains(&middle));
+
+    // A border along the edges, and a card with rounded corners inside them.
+    let img = write_text(
+        text,
+        1,
+        font,
+        ImageOptions {
+            frame: Some(Frame::border(2.0, 0xFF0000)),
+            ..options
+        },
+    );
+    for (x, y) in [(0, 0), (1, 1), (199, height - 1), (100, 1), (198, 30)] {
+        assert_eq!(bmp_pixel(&img, x, y), BitmapPixel(0, 0, 255));
+    }
+    assert_eq!(bmp_pixel(&img, 2, 2), BitmapPixel(255, 255, 255));
+
+    let img = write_text(
+        text,
+        1,
+        font,
+        ImageOptions {
+            frame: Some(Frame::card(4.0, 10.0, 0xEEEEEE)),
+            ..options
+        },
+    );
+    let card = BitmapPixel(0xEE, 0xEE, 0xEE);
+    assert_eq!(bmp_pixel(&img, 3, 30), BitmapPixel(255, 255, 255));
+    assert_eq!(bmp_pixel(&img, 4, 30), card);
+    assert_eq!(bmp_pixel(&img, 15, 15), card);
+    // The corner is cut off.
+    assert_eq!(bmp_pixel(&img, 5, 5), BitmapPixel(255, 255, 255));
+    let edge = bmp_pixel(&img, 4, 10).0;
+    assert!(edge > 0xEE && edge < 0xFF);
+
+    // The scene break is drawn as a rule instead of its text.
+    let rule_row = |img: &[u8]| {
+        (20 + line_height..20 + 2 * line_height)
+            .filter(|&y| (0..width).any(|x| bmp_pixel(img, x, y).0 < 128))
+            .collect::<Vec<_>>()
+    };
+    assert!(rule_row(&plain).len() > 3);
+    let img = write_text(
+        text,
+        1,
+        font,
+        ImageOptions {
+            scene_break: Some("* * *"),
+            ..options
+        },
+    );
+    let rows = rule_row(&img);
+    assert_eq!(rows.len(), 1);
+    let ink: Vec<usize> = (0..width)
+        .filter(|&x| bmp_pixel(&img, x, rows[0]).0 < 128)
+        .collect();
+    assert_eq!(ink.len(), 160 / 4);
+    assert_eq!(ink[0] + ink[ink.len() - 1] + 1, 200);
+
+    let invalid = |options| try_write_text(text, 1, font, options).err();
+    let background = Background {
+        pixels: &pixels[..11],
+        ..tiled
+    };
+    assert!(matches!(
+        invalid(ImageOptions {
+            background: Some(background),
+            ..options
+        }),
+        Some(Error::InvalidBackground { len: 11, .. })
+    ));
+    let frame = Frame::card(-1.0, 2.0, 0);
+    assert_eq!(
+        invalid(ImageOptions {
+            frame: Some(frame),
+            ..options
+        }),
+        Some(Error::InvalidFrame(frame))
+    );
+}
+
+#[test]
+fn illustrations() {
+    let text = include_str!("./lorem.txt");
+    let font = fonts::times::TIMES18;
+    let red: Vec<u8> = [0, 0, 255].repeat(100 * 50);
+    let tall: Vec<u8> = [255, 0, 0].repeat(10 * 100);
+    let illustrations = [
+        Illustration {
+            pixels: &red,
+            width: 100,
+            height: 50,
+            paragraph: 1,
+        },
+        Illustration {
+            pixels: &tall,
+            width: 10,
+            height: 100,
+            paragraph: 1,
+        },
+    ];
+    let options = ImageOptions {
+        page_height: Some(500.0),
+        illustrations: &illustrations,
+        ..Default::default()
+    };
+
+    // Illustrations go before their paragraph in order, scaled to the text width, or
+    // down to the height of a page.
+    let (lines, metrics) = layout::flow(text, &font, &options);
+    let at = lines.iter().position(|l| l.figure.is_some()).unwrap();
+    assert_eq!(lines[at - 1].paragraph, 0);
+    assert_eq!(lines[at + 2].paragraph, 1);
+    let figures = [lines[at].figure.unwrap(), lines[at + 1].figure.unwrap()];
+    assert_eq!(
+        (figures[0].index, figures[0].width, figures[0].height),
+        (0, 760, 380)
+    );
+    assert_eq!(
+        (figures[1].index, figures[1].width, figures[1].height),
+        (1, 46, 460)
+    );
+
+    // They are never split between pages, and nothing overlaps them.
+    let pages = layout::paginate(&lines, &metrics);
+    for figure in [at, at + 1] {
+        let page = pages.iter().position(|p| p.contains(&figure)).unwrap();
+        let range = pages[page].clone();
+        let height = layout::text_height(&lines[range], &metrics);
+        assert!(height <= 460);
+
+        let img = write_text(text, page + 1, font, options);
+        let color = match figure == at {
+            true => BitmapPixel(0, 0, 255),
+            false => BitmapPixel(255, 0, 0),
+        };
+        let (width, height) = bmp_size(&img);
+        let painted = |x: usize| {
+            (0..height as usize)
+                .filter(|&y| bmp_pixel(&img, x, y) == color)
+                .count()
+        };
+        let columns: Vec<usize> = (0..width as usize).filter(|&x| painted(x) > 0).collect();
+        let figure = lines[figure].figure.unwrap();
+        assert_eq!(columns.len(), figure.width);
+        assert_eq!(columns[0], 20 + (760 - figure.width) / 2);
+        assert!(columns.iter().all(|&x| painted(x) == figure.height));
+    }
+
+    // In columns, they are scaled to the width of a column.
+    let options = ImageOptions {
+        columns: 2,
+        ..options
+    };
+    let (lines, _) = layout::flow(text, &font, &options);
+    let figure = lines.iter().find_map(|l| l.figure).unwrap();
+    assert_eq!((figure.width, figure.height), (370, 185));
+
+    // Pages counted in lines hold as much of an illustration as of the text.
+    let towering: Vec<u8> = [255, 0, 0].repeat(10 * 10000);
+    let towering = [Illustration {
+        pixels: &towering,
+        width: 10,
+        height: 10000,
+        paragraph: 1,
+    }];
+    let options = ImageOptions {
+        illustrations: &towering,
+        ..Default::default()
+    };
+    let (lines, metrics) = layout::flow(text, &font, &options);
+    let figure = lines.iter().find_map(|l| l.figure).unwrap();
+    assert_eq!(figure.height, metrics.lines * metrics.line_height);
+    assert!(try_write_text(text, 1, font, options).is_ok());
+
+    // The title only shortens the first page.
+    let title = Title {
+        text: "Title",
+        font: fonts::times::TIMES36,
+        spacing: 10.0,
+    };
+    let first = [
+        Illustration {
+            paragraph: 0,
+            ..towering[0]
+        },
+        towering[0],
+    ];
+    let options = ImageOptions {
+        page_height: Some(500.0),
+        title: Some(title),
+        illustrations: &first,
+        ..Default::default()
+    };
+    let (lines, metrics) = layout::flow(text, &font, &options);
+    let figures: Vec<_> = lines.iter().filter_map(|l| l.figure).collect();
+    assert_eq!(figures[0].height, 460 - metrics.title_height);
+    assert_eq!(figures[1].height, 460);
+    let pages = layout::paginate(&lines, &metrics);
+    for page in 0..pages.len() {
+        let (_, height) = bmp_size(&write_text(text, page + 1, font, options));
+        assert!(height <= 500);
+    }
+
+    let broken = [Illustration {
+        pixels: &red[..10],
+        ..illustrations[0]
+    }];
+    assert!(matches!(
+        try_write_text(
+            text,
+            1,
+            font,
+            ImageOptions {
+                illustrations: &broken,
+                ..options
+            }
+        ),
+        Err(Error::InvalidIllustration {
+            index: 0,
+            len: 10,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn spans() {
+    use typography::{smarten, Language};
+
+    let font = fonts::times::TIMES18;
+    let text = "\"Hi,\" she said -- a café, struck and marked.";
+    let find = |word: &str| {
+        let start = text.find(word).unwrap();
+        start..start + word.len()
+    };
+    let options = ImageOptions {
+        width: 600.0,
+        lines: 1,
+        typography: Some(Language::English),
+        ..Default::default()
+    };
+    let spans = [
+        Span {
+            range: find("café"),
+            decoration: Decoration::Underline,
+        },
+        Span {
+            range: find("struck"),
+            decoration: Decoration::Strikethrough,
+        },
+        Span {
+            range: find("marked"),
+            decoration: Decoration::Highlight(0x00FF00),
+        },
+    ];
+    let plain = write_text(text, 1, font, options);
+    let img = write_text(
+        text,
+        1,
+        font,
+        ImageOptions {
+            spans: &spans,
+            ..options
+        },
+    );
+    let (width, _) = bmp_size(&img);
+
+    // Spans are given over the text passed in, and land on the same words once it has
+    // been transliterated and made typographic.
+    let laid_out = smarten(&filter_text(text, &font), Language::English, &font);
+    let columns = |word: &str| {
+        let start = laid_out.find(word).unwrap();
+        let left = layout::text_advance(&laid_out[..start], &font);
+        let right = left + layout::text_advance(word, &font);
+        let pixel = |x: usize| 20 + (x + 32) / 64;
+        pixel(left)..pixel(right)
+    };
+    let changed = |y: usize| -> Vec<usize> {
+        (0..width as usize)
+            .filter(|&x| bmp_pixel(&img, x, y) != bmp_pixel(&plain, x, y))
+            .collect()
+    };
+    let black = BitmapPixel(0, 0, 0);
+
+    let (underline, thickness) = font.underline();
+    assert!(underline > font.ink_rows('H').unwrap().1);
+    let cafe = columns(&filter_text("café", &font));
+    let struck = columns("struck");
+    let row: Vec<_> = changed(20 + underline)
+        .into_iter()
+        .filter(|&x| x < struck.start)
+        .collect();
+    assert_eq!(
+        row.first().zip(row.last()),
+        Some((&cafe.start, &(cafe.end - 1)))
+    );
+    assert!(cafe
+        .clone()
+        .all(|x| bmp_pixel(&img, x, 20 + underline + thickness - 1) == black));
+
+    let (strikethrough, _) = font.strikethrough();
+    assert!(struck
+        .clone()
+        .all(|x| bmp_pixel(&img, x, 20 + strikethrough) == black));
+    assert!(struck
+        .clone()
+        .any(|x| bmp_pixel(&plain, x, 20 + strikethrough) != black));
+
+    // The highlight fills the line behind the glyphs, which are still drawn over it.
+    let marked = columns("marked");
+    assert_eq!(bmp_pixel(&img, marked.start, 20), BitmapPixel(0, 255, 0));
+    assert_eq!(
+        bmp_pixel(&img, marked.end - 1, 20 + font.line_height() - 1),
+        BitmapPixel(0, 255, 0)
+    );
+    assert_eq!(
+        bmp_pixel(&img, marked.start - 1, 20),
+        bmp_pixel(&plain, marked.start - 1, 20)
+    );
+    assert!(marked.clone().any(|x| bmp_pixel(&img, x, 30) == black));
+    assert_eq!(changed(20 + strikethrough).last(), Some(&(marked.end - 1)));
+
+    // Nothing else changes, and vertical text ignores spans.
+    assert!(changed(5).is_empty() && changed(20 + font.line_height()).is_empty());
+    let vertical = ImageOptions {
+        writing_mode: WritingMode::VerticalRl,
+        page_height: Some(600.0),
+        ..options
+    };
+    assert_eq!(
+        write_text(
+            text,
+            1,
+            font,
+            ImageOptions {
+                spans: &spans,
+                ..vertical
+            }
+        ),
+        write_text(text, 1, font, vertical)
+    );
+}
+
+#[test]
+fn highlights() {
+    let font = fonts::times::TIMES18;
+    let text = "One.\nTwo, café.\nThree.\nFour needles.\nFive.\nSix needles.";
+    let options = ImageOptions {
+        width: 300.0,
+        lines: 2,
+        ..Default::default()
+    };
+    let needle = text.find("needles").unwrap();
+    let chars = text[..needle].chars().count();
+    let last = text.rfind("needles").unwrap();
+    let ranges = [
+        needle..needle + 6,
+        last..last + 6,
+        4..needle,
+        needle..needle,
+        text.len()..text.len() + 3,
+    ];
+    let found = [Some(2), Some(3), Some(1), None, None];
+    let bytes = Highlights::bytes(&ranges, 0x00FFFF);
+    let with = |highlights| ImageOptions {
+        highlights: Some(highlights),
+        ..options
+    };
+    assert_eq!(highlight_pages(text, &font, &with(bytes)), found);
+    assert!(highlight_pages(text, &font, &options).is_empty());
+
+    // Counted in characters, the same words are found past the two-byte é.
+    let hits = [chars..chars + 6, 0..3];
+    let by_chars = Highlights::chars(&hits, 0x00FFFF);
+    assert_eq!(
+        highlight_pages(text, &font, &with(by_chars)),
+        [Some(2), Some(1)]
+    );
+    let vertical = ImageOptions {
+        writing_mode: WritingMode::VerticalRl,
+        width: 82.0,
+        page_height: Some(400.0),
+        ..with(by_chars)
+    };
+    assert_eq!(highlight_pages(text, &font, &vertical), [Some(2), Some(1)]);
+    assert_eq!(
+        try_highlight_pages(" ", &font, &with(bytes)),
+        Err(Error::EmptyText)
+    );
+
+    // The hit is painted behind the glyphs of the second line of its page.
+    let img = write_text(text, 2, font, with(by_chars));
+    assert_eq!(
+        img,
+        write_text(
+            text,
+            2,
+            font,
+            with(Highlights::bytes(&ranges[..1], 0x00FFFF))
+        )
+    );
+    let left = 20 + (layout::text_advance("Four ", &font) + 32) / 64;
+    let right = 20 + (layout::text_advance("Four needle", &font) + 32) / 64;
+    let top = 20 + font.line_height();
+    let cyan = BitmapPixel(255, 255, 0);
+    assert_eq!(bmp_pixel(&img, left, top), cyan);
+    assert_eq!(bmp_pixel(&img, right - 1, top), cyan);
+    assert_ne!(bmp_pixel(&img, right, top), cyan);
+    assert_ne!(bmp_pixel(&img, left - 1, top), cyan);
+    assert!((left..right).any(|x| bmp_pixel(&img, x, top + 10) != cyan));
+    assert!((0..300).all(|x| bmp_pixel(&img, x, top - 1) != cyan));
+}
@@ -0,0 +1,453 @@
+use crate::fonts::Font;
+use alloc::{borrow::Cow, string::String};
+
+/// Replace the characters the font has no glyph for with their ASCII transliteration,
+/// if it has one the font can draw.
+pub(crate) fn transliterate<'a>(text: &'a str, font: &Font) -> Cow<'a, str> {
+    let Some(start) = text.find(|c| replacement(c, font).is_some()) else {
+        return Cow::Borrowed(text);
+    };
+
+    let mut out = String::with_capacity(text.len());
+    out.push_str(&text[..start]);
+    for c in text[start..].chars() {
+        match replacement(c, font) {
+            Some(ascii) => out.push_str(ascii),
+            None => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// What [`transliterate`] replaces the character with, if anything.
+pub(crate) fn replacement(c: char, font: &Font) -> Option<&'static str> {
+    ascii(c).filter(|ascii| !font.has_glyph(c) && ascii.chars().all(|c| font.has_glyph(c)))
+}
+
+/// ASCII spelling of a Latin letter with diacritics, a punctuation mark, or a Cyrillic
+/// or Greek letter.
+fn ascii(c: char) -> Option<&'static str> {
+    TABLE
+        .binary_search_by_key(&c, |&(c, _)| c)
+        .ok()
+        .map(|i| TABLE[i].1)
+}
+
+/// Transliterations sorted by character. Cyrillic and Greek follow common English
+/// romanizations.
+const TABLE: &[(char, &str)] = &[
+    ('\u{00A0}', " "),
+    ('¡', "!"),
+    ('¢', "c"),
+    ('£', "GBP"),
+    ('¥', "JPY"),
+    ('§', "S"),
+    ('©', "(c)"),
+    ('«', "<<"),
+    ('¬', "!"),
+    ('\u{00AD}', ""),
+    ('®', "(r)"),
+    ('°', "deg"),
+    ('±', "+-"),
+    ('²', "2"),
+    ('³', "3"),
+    ('´', "'"),
+    ('µ', "u"),
+    ('¶', "P"),
+    ('·', "."),
+    ('¹', "1"),
+    ('»', ">>"),
+    ('¼', "1/4"),
+    ('½', "1/2"),
+    ('¾', "3/4"),
+    ('¿', "?"),
+    ('À', "A"),
+    ('Á', "A"),
+    ('Â', "A"),
+    ('Ã', "A"),
+    ('Ä', "A"),
+    ('Å', "A"),
+    ('Æ', "AE"),
+    ('Ç', "C"),
+    ('È', "E"),
+    ('É', "E"),
+    ('Ê', "E"),
+    ('Ë', "E"),
+    ('Ì', "I"),
+    ('Í', "I"),
+    ('Î', "I"),
+    ('Ï', "I"),
+    ('Ð', "D"),
+    ('Ñ', "N"),
+    ('Ò', "O"),
+    ('Ó', "O"),
+    ('Ô', "O"),
+    ('Õ', "O"),
+    ('Ö', "O"),
+    ('×', "x"),
+    ('Ø', "O"),
+    ('Ù', "U"),
+    ('Ú', "U"),
+    ('Û', "U"),
+    ('Ü', "U"),
+    ('Ý', "Y"),
+    ('Þ', "Th"),
+    ('ß', "ss"),
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('ä', "a"),
+    ('å', "a"),
+    ('æ', "ae"),
+    ('ç', "c"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ð', "d"),
+    ('ñ', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ö', "o"),
+    ('÷', "/"),
+    ('ø', "o"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ü', "u"),
+    ('ý', "y"),
+    ('þ', "th"),
+    ('ÿ', "y"),
+    ('Ā', "A"),
+    ('ā', "a"),
+    ('Ă', "A"),
+    ('ă', "a"),
+    ('Ą', "A"),
+    ('ą', "a"),
+    ('Ć', "C"),
+    ('ć', "c"),
+    ('Ĉ', "C"),
+    ('ĉ', "c"),
+    ('Ċ', "C"),
+    ('ċ', "c"),
+    ('Č', "C"),
+    ('č', "c"),
+    ('Ď', "D"),
+    ('ď', "d"),
+    ('Đ', "D"),
+    ('đ', "d"),
+    ('Ē', "E"),
+    ('ē', "e"),
+    ('Ĕ', "E"),
+    ('ĕ', "e"),
+    ('Ė', "E"),
+    ('ė', "e"),
+    ('Ę', "E"),
+    ('ę', "e"),
+    ('Ě', "E"),
+    ('ě', "e"),
+    ('Ĝ', "G"),
+    ('ĝ', "g"),
+    ('Ğ', "G"),
+    ('ğ', "g"),
+    ('Ġ', "G"),
+    ('ġ', "g"),
+    ('Ģ', "G"),
+    ('ģ', "g"),
+    ('Ĥ', "H"),
+    ('ĥ', "h"),
+    ('Ħ', "H"),
+    ('ħ', "h"),
+    ('Ĩ', "I"),
+    ('ĩ', "i"),
+    ('Ī', "I"),
+    ('ī', "i"),
+    ('Ĭ', "I"),
+    ('ĭ', "i"),
+    ('Į', "I"),
+    ('į', "i"),
+    ('İ', "I"),
+    ('ı', "i"),
+    ('Ĳ', "IJ"),
+    ('ĳ', "ij"),
+    ('Ĵ', "J"),
+    ('ĵ', "j"),
+    ('Ķ', "K"),
+    ('ķ', "k"),
+    ('ĸ', "q"),
+    ('Ĺ', "L"),
+    ('ĺ', "l"),
+    ('Ļ', "L"),
+    ('ļ', "l"),
+    ('Ľ', "L"),
+    ('ľ', "l"),
+    ('Ŀ', "L"),
+    ('ŀ', "l"),
+    ('Ł', "L"),
+    ('ł', "l"),
+    ('Ń', "N"),
+    ('ń', "n"),
+    ('Ņ', "N"),
+    ('ņ', "n"),
+    ('Ň', "N"),
+    ('ň', "n"),
+    ('ŉ', "'n"),
+    ('Ŋ', "NG"),
+    ('ŋ', "ng"),
+    ('Ō', "O"),
+    ('ō', "o"),
+    ('Ŏ', "O"),
+    ('ŏ', "o"),
+    ('Ő', "O"),
+    ('ő', "o"),
+    ('Œ', "OE"),
+    ('œ', "oe"),
+    ('Ŕ', "R"),
+    ('ŕ', "r"),
+    ('Ŗ', "R"),
+    ('ŗ', "r"),
+    ('Ř', "R"),
+    ('ř', "r"),
+    ('Ś', "S"),
+    ('ś', "s"),
+    ('Ŝ', "S"),
+    ('ŝ', "s"),
+    ('Ş', "S"),
+    ('ş', "s"),
+    ('Š', "S"),
+    ('š', "s"),
+    ('Ţ', "T"),
+    ('ţ', "t"),
+    ('Ť', "T"),
+    ('ť', "t"),
+    ('Ŧ', "T"),
+    ('ŧ', "t"),
+    ('Ũ', "U"),
+    ('ũ', "u"),
+    ('Ū', "U"),
+    ('ū', "u"),
+    ('Ŭ', "U"),
+    ('ŭ', "u"),
+    ('Ů', "U"),
+    ('ů', "u"),
+    ('Ű', "U"),
+    ('ű', "u"),
+    ('Ų', "U"),
+    ('ų', "u"),
+    ('Ŵ', "W"),
+    ('ŵ', "w"),
+    ('Ŷ', "Y"),
+    ('ŷ', "y"),
+    ('Ÿ', "Y"),
+    ('Ź', "Z"),
+    ('ź', "z"),
+    ('Ż', "Z"),
+    ('ż', "z"),
+    ('Ž', "Z"),
+    ('ž', "z"),
+    ('ſ', "s"),
+    ('ƒ', "f"),
+    ('Ș', "S"),
+    ('ș', "s"),
+    ('Ț', "T"),
+    ('ț', "t"),
+    ('Ά', "A"),
+    ('Έ', "E"),
+    ('Ή', "I"),
+    ('Ί', "I"),
+    ('Ό', "O"),
+    ('Ύ', "Y"),
+    ('Ώ', "O"),
+    ('ΐ', "i"),
+    ('Α', "A"),
+    ('Β', "V"),
+    ('Γ', "G"),
+    ('Δ', "D"),
+    ('Ε', "E"),
+    ('Ζ', "Z"),
+    ('Η', "I"),
+    ('Θ', "Th"),
+    ('Ι', "I"),
+    ('Κ', "K"),
+    ('Λ', "L"),
+    ('Μ', "M"),
+    ('Ν', "N"),
+    ('Ξ', "X"),
+    ('Ο', "O"),
+    ('Π', "P"),
+    ('Ρ', "R"),
+    ('Σ', "S"),
+    ('Τ', "T"),
+    ('Υ', "Y"),
+    ('Φ', "F"),
+    ('Χ', "Ch"),
+    ('Ψ', "Ps"),
+    ('Ω', "O"),
+    ('Ϊ', "I"),
+    ('Ϋ', "Y"),
+    ('ά', "a"),
+    ('έ', "e"),
+    ('ή', "i"),
+    ('ί', "i"),
+    ('ΰ', "y"),
+    ('α', "a"),
+    ('β', "v"),
+    ('γ', "g"),
+    ('δ', "d"),
+    ('ε', "e"),
+    ('ζ', "z"),
+    ('η', "i"),
+    ('θ', "th"),
+    ('ι', "i"),
+    ('κ', "k"),
+    ('λ', "l"),
+    ('μ', "m"),
+    ('ν', "n"),
+    ('ξ', "x"),
+    ('ο', "o"),
+    ('π', "p"),
+    ('ρ', "r"),
+    ('ς', "s"),
+    ('σ', "s"),
+    ('τ', "t"),
+    ('υ', "y"),
+    ('φ', "f"),
+    ('χ', "ch"),
+    ('ψ', "ps"),
+    ('ω', "o"),
+    ('ϊ', "i"),
+    ('ϋ', "y"),
+    ('ό', "o"),
+    ('ύ', "y"),
+    ('ώ', "o"),
+    ('Ё', "Yo"),
+    ('Ђ', "Dj"),
+    ('Є', "Ye"),
+    ('Ѕ', "Dz"),
+    ('І', "I"),
+    ('Ї', "Yi"),
+    ('Ј', "J"),
+    ('Љ', "Lj"),
+    ('Њ', "Nj"),
+    ('Ћ', "C"),
+    ('Ў', "U"),
+    ('Џ', "Dz"),
+    ('А', "A"),
+    ('Б', "B"),
+    ('В', "V"),
+    ('Г', "G"),
+    ('Д', "D"),
+    ('Е', "E"),
+    ('Ж', "Zh"),
+    ('З', "Z"),
+    ('И', "I"),
+    ('Й', "Y"),
+    ('К', "K"),
+    ('Л', "L"),
+    ('М', "M"),
+    ('Н', "N"),
+    ('О', "O"),
+    ('П', "P"),
+    ('Р', "R"),
+    ('С', "S"),
+    ('Т', "T"),
+    ('У', "U"),
+    ('Ф', "F"),
+    ('Х', "Kh"),
+    ('Ц', "Ts"),
+    ('Ч', "Ch"),
+    ('Ш', "Sh"),
+    ('Щ', "Shch"),
+    ('Ъ', ""),
+    ('Ы', "Y"),
+    ('Ь', ""),
+    ('Э', "E"),
+    ('Ю', "Yu"),
+    ('Я', "Ya"),
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('е', "e"),
+    ('ж', "zh"),
+    ('з', "z"),
+    ('и', "i"),
+    ('й', "y"),
+    ('к', "k"),
+    ('л', "l"),
+    ('м', "m"),
+    ('н', "n"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "kh"),
+    ('ц', "ts"),
+    ('ч', "ch"),
+    ('ш', "sh"),
+    ('щ', "shch"),
+    ('ъ', ""),
+    ('ы', "y"),
+    ('ь', ""),
+    ('э', "e"),
+    ('ю', "yu"),
+    ('я', "ya"),
+    ('ё', "yo"),
+    ('ђ', "dj"),
+    ('є', "ye"),
+    ('ѕ', "dz"),
+    ('і', "i"),
+    ('ї', "yi"),
+    ('ј', "j"),
+    ('љ', "lj"),
+    ('њ', "nj"),
+    ('ћ', "c"),
+    ('ў', "u"),
+    ('џ', "dz"),
+    ('Ґ', "G"),
+    ('ґ', "g"),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{200B}', ""),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('‘', "'"),
+    ('’', "'"),
+    ('‚', ","),
+    ('‛', "'"),
+    ('“', "\""),
+    ('”', "\""),
+    ('„', ",,"),
+    ('‟', "\""),
+    ('†', "+"),
+    ('•', "*"),
+    ('…', "..."),
+    ('\u{202F}', " "),
+    ('‰', "%0"),
+    ('′', "'"),
+    ('″', "\""),
+    ('‹', "<"),
+    ('›', ">"),
+    ('\u{2060}', ""),
+    ('€', "EUR"),
+    ('™', "TM"),
+    ('−', "-"),
+    ('\u{FEFF}', ""),
+];
@@ -0,0 +1,175 @@
+use crate::{fonts::Font, mapped::Mapped};
+use alloc::{string::String, vec::Vec};
+
+/// Languages whose typographic conventions [`smarten`] follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Language {
+    /// “Double” and ‘single’ quotes.
+    #[default]
+    English,
+
+    /// « Guillemets » and “double” quotes, with no-break spaces inside guillemets and
+    /// before `:`, `;`, `!` and `?`.
+    French,
+
+    /// „Low“ and ‚single low‘ quotes.
+    German,
+}
+
+impl Language {
+    /// Opening and closing characters for outer and inner quotes.
+    fn quotes(self) -> [(char, char); 2] {
+        match self {
+            Language::English => [('“', '”'), ('‘', '’')],
+            Language::French => [('«', '»'), ('“', '”')],
+            Language::German => [('„', '“'), ('‚', '‘')],
+        }
+    }
+}
+
+const APOSTROPHE: char = '’';
+const ELLIPSIS: char = '…';
+const EN_DASH: char = '–';
+const EM_DASH: char = '—';
+const NO_BREAK_SPACE: char = '\u{00A0}';
+const NARROW_NO_BREAK_SPACE: char = '\u{202F}';
+
+/// Replace typewriter punctuation with its typographic form: straight quotes with
+/// curly quotes and apostrophes, `--` and `---` with dashes, `...` with an ellipsis,
+/// and, for French, spaces before punctuation with no-break spaces.
+///
+/// A `--` between digits becomes an en dash, as does a hyphen with a space on either
+/// side, and any other `--` an em dash. Characters the font has no glyph for are left
+/// as they are.
+pub fn smarten(text: &str, language: Language, font: &Font) -> String {
+    smarten_mapped(text, language, font, false).text
+}
+
+/// [`smarten`], tracking where each byte of the result came from if `track` is set.
+pub(crate) fn smarten_mapped(text: &str, language: Language, font: &Font, track: bool) -> Mapped {
+    let has = |c| font.has_glyph(c);
+    let quotes = language
+        .quotes()
+        .map(|(open, close)| match has(open) && has(close) {
+            true => Some((open, close)),
+            false => None,
+        });
+    let french = language == Language::French;
+
+    let (offsets, chars): (Vec<usize>, Vec<char>) = text.char_indices().unzip();
+    let mut out = Mapped::new(text.len(), track);
+    let mut is_open = [false; 2];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let origin = offsets[i];
+        let previous = out.text.chars().next_back();
+        let next = chars.get(i + 1).copied();
+        let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+        i += 1;
+
+        match c {
+            '.' if chars[i..].starts_with(&['.', '.']) && has(ELLIPSIS) => {
+                out.push(ELLIPSIS, origin);
+                i += 2;
+            }
+            '-' if next == Some('-') => {
+                let long = chars.get(i + 1) == Some(&'-');
+                let dash = match long {
+                    false if is_digit(previous) && is_digit(chars.get(i + 1).copied()) => EN_DASH,
+                    _ => EM_DASH,
+                };
+                if has(dash) {
+                    out.push(dash, origin);
+                    i += 1 + long as usize;
+                } else {
+                    out.push(c, origin);
+                }
+            }
+            '-' if previous == Some(' ') && next == Some(' ') && has(EN_DASH) => {
+                out.push(EN_DASH, origin)
+            }
+            '\'' if is_letter(previous) && is_letter(next) && has(APOSTROPHE) => {
+                out.push(APOSTROPHE, origin)
+            }
+            '"' | '\'' => {
+                let kind = (c == '\'') as usize;
+                let Some((open, close)) = quotes[kind] else {
+                    out.push(c, origin);
+                    continue;
+                };
+                // An open quote is closed by the next one, unless that one starts a new
+                // quote and the first was never closed.
+                is_open[kind] = match is_open[kind] {
+                    true => opens(previous) && is_word_char(next),
+                    false => opens(previous),
+                };
+                if is_open[kind] {
+                    out.push(open, origin);
+                    // The spaces after it are only replaced if there is a no-break
+                    // space to put instead.
+                    if french && open == '«' && has(NO_BREAK_SPACE) {
+                        while chars.get(i) == Some(&' ') {
+                            i += 1;
+                        }
+                        push_space(&mut out, NO_BREAK_SPACE, origin, font);
+                    }
+                } else {
+                    if french && close == '»' {
+                        push_space(&mut out, NO_BREAK_SPACE, origin, font);
+                    }
+                    out.push(close, origin);
+                }
+            }
+            ':' | ';' | '!' | '?' if french && !is_word_char(next) && !is_punctuation(previous) => {
+                // A colon gets a full space, the others a narrow one.
+                let space = match c {
+                    ':' => NO_BREAK_SPACE,
+                    _ => NARROW_NO_BREAK_SPACE,
+                };
+                push_space(&mut out, space, origin, font);
+                out.push(c, origin);
+            }
+            '\n' => {
+                // Quotes left open run on into the next paragraph, which opens them again.
+                is_open = [false; 2];
+                out.push(c, origin);
+            }
+            _ => out.push(c, origin),
+        }
+    }
+    out.finish(text.len())
+}
+
+/// Replace the spaces at the end of `out` with the given no-break space, or add one.
+fn push_space(out: &mut Mapped, space: char, origin: usize, font: &Font) {
+    if !font.has_glyph(space) || out.text.is_empty() {
+        return;
+    }
+    let trimmed = out
+        .text
+        .trim_end_matches([' ', NO_BREAK_SPACE, NARROW_NO_BREAK_SPACE]);
+    out.truncate(trimmed.len());
+    out.push(space, origin);
+}
+
+/// Whether a quote after the given character opens a quotation.
+fn opens(previous: Option<char>) -> bool {
+    match previous {
+        None => true,
+        Some(c) => c.is_whitespace() || "([{<-–—“‘„‚«".contains(c),
+    }
+}
+
+fn is_letter(c: Option<char>) -> bool {
+    c.is_some_and(char::is_alphabetic)
+}
+
+fn is_word_char(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric() || c == '/')
+}
+
+fn is_punctuation(c: Option<char>) -> bool {
+    c.is_some_and(|c| ":;!?".contains(c))
+}
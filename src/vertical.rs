@@ -0,0 +1,64 @@
+use crate::fonts::{Font, SUBPIXELS};
+
+/// How a character is set in vertical text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Orientation {
+    /// Standing up, centered in the column, as CJK characters are.
+    Upright,
+    /// Turned 90° clockwise, as Latin text and the brackets and dashes that follow the
+    /// direction of the text are.
+    Sideways,
+    /// Standing up, but moved half a cell up and right, as the ideographic comma and full
+    /// stop are, from the bottom left corner they sit in beside horizontal text to the
+    /// top right one.
+    Raised,
+}
+
+/// Wide punctuation that is turned sideways in vertical text.
+const SIDEWAYS: &[char] = &[
+    '〈', '〉', '《', '》', '「', '」', '『', '』', '【', '】', '〔', '〕', '〖', '〗', '〘', '〙',
+    '〚', '〛', '〜', 'ー', '（', '）', '－', '：', '；', '＜', '＝', '＞', '［', '］', '｛', '｜',
+    '｝', '～', '｟', '｠',
+];
+
+/// Wide punctuation that is raised to the top right of its cell in vertical text.
+const RAISED: &[char] = &['、', '。'];
+
+pub(crate) fn orientation(c: char) -> Orientation {
+    if RAISED.contains(&c) {
+        Orientation::Raised
+    } else if is_wide(c) && SIDEWAYS.binary_search(&c).is_err() {
+        Orientation::Upright
+    } else {
+        Orientation::Sideways
+    }
+}
+
+/// Whether the character is one of the wide East Asian characters.
+pub(crate) fn is_wide(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA000..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+            | 0x20000..=0x2FFFD
+            | 0x30000..=0x3FFFD
+    )
+}
+
+/// Distance from the glyph for the given character to the next one down the column, in
+/// 1/64 of a pixel.
+pub(crate) fn advance(font: &Font, c: char) -> usize {
+    match orientation(c) {
+        Orientation::Upright | Orientation::Raised => font.line_height() * SUBPIXELS,
+        Orientation::Sideways => font.advance(c),
+    }
+}
@@ -0,0 +1,648 @@
+use crate::{page::Page, Error, Write};
+use alloc::{vec, vec::Vec};
+
+/// Largest width or height a WebP image can have.
+const MAX_SIZE: usize = 1 << 14;
+
+const SIGNATURE: u8 = 0x2F;
+const COLOR_INDEXING_TRANSFORM: u32 = 3;
+
+/// Size of the RIFF header and the VP8L chunk header.
+const RIFF_HEADER_SIZE: usize = 20;
+/// Generous allowance for the VP8L header, the palette and the prefix codes. The
+/// palette takes at most 256 literals, and each of the ten prefix codes of the palette
+/// and the main image at most 1304 code lengths of 14 bits, well under 32 KiB together.
+const MAX_HEADERS_SIZE: usize = 1 << 16;
+/// Most bits a pixel takes. No symbol is longer than [`MAX_CODE_LENGTH`], and a literal
+/// is coded as green, red and blue, alpha having a single value which takes no bits. A
+/// cached color is one symbol, and a copy two symbols with at most 10 and 18 extra bits,
+/// shared by at least [`MIN_LENGTH`] pixels. Packed pixels are all green.
+const MAX_PIXEL_BITS: usize = 3 * MAX_CODE_LENGTH as usize;
+
+const NUM_LITERALS: usize = 256;
+const NUM_LENGTH_CODES: usize = 24;
+const NUM_DISTANCE_CODES: usize = 40;
+/// Number of distance codes reserved for the neighborhood of a pixel.
+const NUM_PLANE_CODES: usize = 120;
+/// Largest distance that still fits in the distance codes.
+const MAX_DISTANCE: usize = (1 << 20) - NUM_PLANE_CODES;
+
+const MIN_LENGTH: usize = 3;
+const MAX_LENGTH: usize = 4096;
+const HASH_BITS: u32 = 15;
+
+const MAX_CACHE_BITS: u32 = 10;
+const MAX_CODE_LENGTH: u8 = 15;
+const MAX_CODE_LENGTH_CODE_LENGTH: u8 = 7;
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    17, 18, 0, 1, 2, 3, 4, 5, 16, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+];
+
+/// Get the size of the WebP file for an image of the given size, in the worst case
+/// where nothing compresses.
+pub(crate) fn encoded_size(width: usize, height: usize) -> Result<usize, Error> {
+    if width > MAX_SIZE || height > MAX_SIZE {
+        return Err(Error::ImageTooLarge { width, height });
+    }
+    Ok(RIFF_HEADER_SIZE + MAX_HEADERS_SIZE + (width * height * MAX_PIXEL_BITS).div_ceil(8))
+}
+
+/// Encode the page as a lossless WebP file.
+///
+/// Images with up to 256 colors, which is all of them unless the text is blended onto
+/// more than one background, use the color indexing transform and pack several pixels
+/// into one. The pixels are then compressed with backward references to the row above,
+/// the pixel on the left and recently seen sequences, plus a color cache when it helps.
+pub(crate) fn encode<W: Write>(page: &Page, writer: &mut W) -> Result<(), Error> {
+    encoded_size(page.width, page.height)?;
+
+    let mut pixels = Vec::with_capacity(page.width * page.height);
+    page.rows(false, |row| {
+        pixels.extend(row.chunks_exact(3).map(|p| argb(p[2], p[1], p[0])));
+        Ok(())
+    })?;
+
+    encode_pixels(pixels, page.width, page.height, writer)
+}
+
+/// Encode an image of ARGB pixels as a lossless WebP file.
+pub(crate) fn encode_pixels<W: Write>(
+    pixels: Vec<u32>,
+    width: usize,
+    height: usize,
+    writer: &mut W,
+) -> Result<(), Error> {
+    let mut data = compress(pixels, width, height);
+    // The chunk size leaves out the padding byte, the file size doesn't.
+    let chunk_size = data.len();
+    if chunk_size % 2 == 1 {
+        data.push(0);
+    }
+
+    let mut header = [0; RIFF_HEADER_SIZE];
+    header[..4].copy_from_slice(b"RIFF");
+    header[4..8].copy_from_slice(&((data.len() + 12) as u32).to_le_bytes());
+    header[8..12].copy_from_slice(b"WEBP");
+    header[12..16].copy_from_slice(b"VP8L");
+    header[16..20].copy_from_slice(&(chunk_size as u32).to_le_bytes());
+    writer.write_all(&header)?;
+    writer.write_all(&data)
+}
+
+/// Compress an image of ARGB pixels into a VP8L bitstream.
+fn compress(pixels: Vec<u32>, width: usize, height: usize) -> Vec<u8> {
+    let mut w = BitWriter::default();
+    w.write(SIGNATURE as u32, 8);
+    w.write(width as u32 - 1, 14);
+    w.write(height as u32 - 1, 14);
+    w.write(0, 1); // alpha is unused
+    w.write(0, 3); // version
+
+    let (pixels, xsize) = match palette(&pixels) {
+        Some(palette) => {
+            w.write(1, 1);
+            w.write(COLOR_INDEXING_TRANSFORM, 2);
+            w.write(palette.len() as u32 - 1, 8);
+            // The palette is stored as an image, each color relative to the previous one.
+            let deltas: Vec<u32> = palette
+                .iter()
+                .scan(0, |previous, &color| {
+                    let delta = subtract(color, *previous);
+                    *previous = color;
+                    Some(delta)
+                })
+                .collect();
+            let tokens: Vec<Token> = deltas.iter().map(|&p| Token::Literal(p)).collect();
+            write_image(&mut w, &deltas, &tokens, 0, false);
+
+            bundle(&pixels, width, &palette)
+        }
+        None => (pixels, width),
+    };
+    w.write(0, 1); // no more transforms
+
+    let tokens = backward_references(&pixels, xsize);
+    let cache_bits = (0..=MAX_CACHE_BITS)
+        .min_by_key(|&bits| estimate_cost(&pixels, &tokens, bits))
+        .unwrap_or(0);
+    write_image(&mut w, &pixels, &tokens, cache_bits, true);
+    w.finish()
+}
+
+pub(crate) fn argb(r: u8, g: u8, b: u8) -> u32 {
+    0xFF00_0000 | (r as u32) << 16 | (g as u32) << 8 | b as u32
+}
+
+/// Subtract each channel of `b` from `a`.
+fn subtract(a: u32, b: u32) -> u32 {
+    let [a0, a1, a2, a3] = a.to_le_bytes();
+    let [b0, b1, b2, b3] = b.to_le_bytes();
+    u32::from_le_bytes([
+        a0.wrapping_sub(b0),
+        a1.wrapping_sub(b1),
+        a2.wrapping_sub(b2),
+        a3.wrapping_sub(b3),
+    ])
+}
+
+/// The sorted colors of the image, if there are few enough for the color indexing
+/// transform.
+fn palette(pixels: &[u32]) -> Option<Vec<u32>> {
+    let mut palette = Vec::new();
+    let mut last = None;
+    for &pixel in pixels {
+        if last == Some(pixel) {
+            continue;
+        }
+        last = Some(pixel);
+        if let Err(i) = palette.binary_search(&pixel) {
+            if palette.len() == 256 {
+                return None;
+            }
+            palette.insert(i, pixel);
+        }
+    }
+    Some(palette)
+}
+
+/// Replace the pixels with their palette indices, packing as many as fit in the green
+/// channel of each pixel. Returns the packed pixels and their row width.
+fn bundle(pixels: &[u32], width: usize, palette: &[u32]) -> (Vec<u32>, usize) {
+    let width_bits = match palette.len() {
+        0..=2 => 3,
+        3..=4 => 2,
+        5..=16 => 1,
+        _ => 0,
+    };
+    let bits = 8 >> width_bits;
+    let mask = (1 << width_bits) - 1;
+    let xsize = width.div_ceil(1 << width_bits);
+
+    let mut packed = vec![0; xsize * (pixels.len() / width)];
+    let index = |pixel| palette.binary_search(&pixel).unwrap_or(0) as u32;
+    let mut last = (pixels[0], index(pixels[0]));
+    for (row, out) in pixels
+        .chunks_exact(width)
+        .zip(packed.chunks_exact_mut(xsize))
+    {
+        for (x, &pixel) in row.iter().enumerate() {
+            if last.0 != pixel {
+                last = (pixel, index(pixel));
+            }
+            out[x >> width_bits] |= last.1 << (bits * (x & mask));
+        }
+    }
+    for pixel in &mut packed {
+        *pixel = 0xFF00_0000 | *pixel << 8;
+    }
+    (packed, xsize)
+}
+
+/// A piece of the compressed pixel data.
+#[derive(Debug, Clone, Copy)]
+enum Token {
+    Literal(u32),
+    /// Repeat `length` pixels starting `distance` pixels back, with the distance already
+    /// converted to its distance code.
+    Copy {
+        length: usize,
+        distance: usize,
+    },
+}
+
+/// Greedily split the pixels into literals and copies of earlier pixels.
+fn backward_references(pixels: &[u32], xsize: usize) -> Vec<Token> {
+    let hash = |i: usize| {
+        let key = pixels[i] ^ pixels[i + 1].rotate_left(11) ^ pixels[i + 2].rotate_left(22);
+        (key.wrapping_mul(0x1E35_A7BD) >> (32 - HASH_BITS)) as usize
+    };
+    let mut recent = vec![usize::MAX; 1 << HASH_BITS];
+    let mut insert = |i: usize| {
+        if i + MIN_LENGTH <= pixels.len() {
+            let previous = core::mem::replace(&mut recent[hash(i)], i);
+            Some(previous)
+        } else {
+            None
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < pixels.len() {
+        let hashed = insert(i)
+            .filter(|&previous| previous != usize::MAX)
+            .map_or(0, |previous| i - previous);
+        let mut best = (0, 0);
+        for distance in [1, xsize, hashed] {
+            if distance == 0 || distance > i || distance > MAX_DISTANCE {
+                continue;
+            }
+            let length = pixels[i..]
+                .iter()
+                .zip(&pixels[i - distance..])
+                .take(MAX_LENGTH)
+                .take_while(|(a, b)| a == b)
+                .count();
+            if length > best.0 {
+                best = (length, distance);
+            }
+        }
+
+        if best.0 >= MIN_LENGTH {
+            let (length, distance) = best;
+            tokens.push(Token::Copy {
+                length,
+                distance: distance_code(distance, xsize),
+            });
+            for j in i + 1..i + length {
+                insert(j);
+            }
+            i += length;
+        } else {
+            tokens.push(Token::Literal(pixels[i]));
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Map a distance to its distance code, using the short codes for the pixel above and
+/// the one on the left.
+fn distance_code(distance: usize, xsize: usize) -> usize {
+    if distance == xsize {
+        1
+    } else if distance == 1 {
+        2
+    } else {
+        distance + NUM_PLANE_CODES
+    }
+}
+
+/// Split a length or distance code into its prefix symbol, extra bit count and extra
+/// bits.
+fn prefix(value: usize) -> (usize, u32, u32) {
+    let value = value - 1;
+    if value < 4 {
+        return (value, 0, 0);
+    }
+    let high = usize::BITS - 1 - value.leading_zeros();
+    let second = (value >> (high - 1)) & 1;
+    let extra_bits = high - 1;
+    (
+        2 * high as usize + second,
+        extra_bits,
+        (value & ((1 << extra_bits) - 1)) as u32,
+    )
+}
+
+/// A token as it is coded with a particular color cache.
+enum Symbol {
+    Literal(u32),
+    Cache(usize),
+    Copy { length: usize, distance: usize },
+}
+
+/// Walk the tokens, calling `f` with the symbol each one is coded as when using a color
+/// cache of `cache_bits`, or none if zero.
+fn for_each_symbol(pixels: &[u32], tokens: &[Token], cache_bits: u32, mut f: impl FnMut(Symbol)) {
+    let mut cache = vec![0; 1 << cache_bits];
+    let key = |pixel: u32| (pixel.wrapping_mul(0x1E35_A7BD) >> (32 - cache_bits)) as usize;
+
+    let mut i = 0;
+    for &token in tokens {
+        match token {
+            Token::Literal(pixel) => {
+                if cache_bits == 0 {
+                    f(Symbol::Literal(pixel));
+                } else if cache[key(pixel)] == pixel {
+                    f(Symbol::Cache(key(pixel)));
+                } else {
+                    cache[key(pixel)] = pixel;
+                    f(Symbol::Literal(pixel));
+                }
+                i += 1;
+            }
+            Token::Copy { length, distance } => {
+                if cache_bits > 0 {
+                    for &pixel in &pixels[i..i + length] {
+                        cache[key(pixel)] = pixel;
+                    }
+                }
+                f(Symbol::Copy { length, distance });
+                i += length;
+            }
+        }
+    }
+}
+
+/// Symbol counts for the five prefix codes of an image: green with lengths and cache
+/// indices, red, blue, alpha and distance.
+struct Histograms([Vec<u32>; 5]);
+
+impl Histograms {
+    fn new(pixels: &[u32], tokens: &[Token], cache_bits: u32) -> Self {
+        let cache_size = if cache_bits > 0 { 1 << cache_bits } else { 0 };
+        let mut histograms = [
+            vec![0; NUM_LITERALS + NUM_LENGTH_CODES + cache_size],
+            vec![0; NUM_LITERALS],
+            vec![0; NUM_LITERALS],
+            vec![0; NUM_LITERALS],
+            vec![0; NUM_DISTANCE_CODES],
+        ];
+        for_each_symbol(pixels, tokens, cache_bits, |symbol| match symbol {
+            Symbol::Literal(pixel) => {
+                let [b, g, r, a] = pixel.to_le_bytes();
+                for (histogram, channel) in histograms[..4].iter_mut().zip([g, r, b, a]) {
+                    histogram[channel as usize] += 1;
+                }
+            }
+            Symbol::Cache(index) => histograms[0][NUM_LITERALS + NUM_LENGTH_CODES + index] += 1,
+            Symbol::Copy { length, distance } => {
+                histograms[0][NUM_LITERALS + prefix(length).0] += 1;
+                histograms[4][prefix(distance).0] += 1;
+            }
+        });
+        Self(histograms)
+    }
+}
+
+/// Estimate the number of bits the symbols take with a color cache of `cache_bits`.
+fn estimate_cost(pixels: &[u32], tokens: &[Token], cache_bits: u32) -> u64 {
+    Histograms::new(pixels, tokens, cache_bits)
+        .0
+        .iter()
+        .map(|histogram| {
+            let lengths = code_lengths(histogram, MAX_CODE_LENGTH);
+            histogram
+                .iter()
+                .zip(lengths)
+                .map(|(&count, length)| count as u64 * length as u64)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+/// Write an entropy-coded image: the color cache, the prefix codes and the pixels. Only
+/// the main image can have meta prefix codes, which are never used here.
+fn write_image(w: &mut BitWriter, pixels: &[u32], tokens: &[Token], cache_bits: u32, main: bool) {
+    if cache_bits > 0 {
+        w.write(1, 1);
+        w.write(cache_bits, 4);
+    } else {
+        w.write(0, 1);
+    }
+    if main {
+        w.write(0, 1);
+    }
+
+    let codes = Histograms::new(pixels, tokens, cache_bits)
+        .0
+        .map(|histogram| PrefixCode::new(&histogram, MAX_CODE_LENGTH));
+    for code in &codes {
+        code.write_header(w);
+    }
+
+    let [green, red, blue, alpha, distances] = &codes;
+    for_each_symbol(pixels, tokens, cache_bits, |symbol| match symbol {
+        Symbol::Literal(pixel) => {
+            let [b, g, r, a] = pixel.to_le_bytes();
+            green.write_symbol(w, g as usize);
+            red.write_symbol(w, r as usize);
+            blue.write_symbol(w, b as usize);
+            alpha.write_symbol(w, a as usize);
+        }
+        Symbol::Cache(index) => green.write_symbol(w, NUM_LITERALS + NUM_LENGTH_CODES + index),
+        Symbol::Copy { length, distance } => {
+            let (symbol, bits, extra) = prefix(length);
+            green.write_symbol(w, NUM_LITERALS + symbol);
+            w.write(extra, bits);
+            let (symbol, bits, extra) = prefix(distance);
+            distances.write_symbol(w, symbol);
+            w.write(extra, bits);
+        }
+    });
+}
+
+/// Huffman code lengths for the given symbol counts, no longer than `limit`.
+///
+/// Codes that turn out too long are rebuilt with the smallest counts raised, until they
+/// fit. A lone symbol gets a length of one, though it is coded with no bits at all.
+fn code_lengths(histogram: &[u32], limit: u8) -> Vec<u8> {
+    let mut lengths = vec![0; histogram.len()];
+    let mut floor = 1;
+    loop {
+        let mut leaves: Vec<(u64, usize)> = histogram
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(symbol, &count)| (count.max(floor) as u64, symbol))
+            .collect();
+        if leaves.len() <= 1 {
+            if let Some(&(_, symbol)) = leaves.first() {
+                lengths[symbol] = 1;
+            }
+            return lengths;
+        }
+        leaves.sort_unstable();
+
+        // Leaves come first, then internal nodes in the order they are created, which is
+        // also by increasing weight.
+        let n = leaves.len();
+        let mut weights: Vec<u64> = leaves.iter().map(|&(weight, _)| weight).collect();
+        let mut parents = vec![0; 2 * n - 1];
+        let (mut leaf, mut node) = (0, n);
+        for parent in n..2 * n - 1 {
+            let mut smallest = || {
+                if leaf < n && (node >= parent || weights[leaf] <= weights[node]) {
+                    leaf += 1;
+                    leaf - 1
+                } else {
+                    node += 1;
+                    node - 1
+                }
+            };
+            let (a, b) = (smallest(), smallest());
+            parents[a] = parent;
+            parents[b] = parent;
+            weights.push(weights[a] + weights[b]);
+        }
+
+        let mut depths = vec![0u8; 2 * n - 1];
+        for i in (0..2 * n - 2).rev() {
+            depths[i] = depths[parents[i]] + 1;
+        }
+        if depths[..n].iter().all(|&depth| depth <= limit) {
+            for (&(_, symbol), &depth) in leaves.iter().zip(&depths) {
+                lengths[symbol] = depth;
+            }
+            return lengths;
+        }
+        floor *= 2;
+    }
+}
+
+/// A canonical prefix code.
+struct PrefixCode {
+    lengths: Vec<u8>,
+    /// Codes with their bits reversed, as they are written least significant bit first.
+    codes: Vec<u16>,
+    /// Set when the code has a single symbol, which takes no bits.
+    single: bool,
+}
+
+impl PrefixCode {
+    fn new(histogram: &[u32], limit: u8) -> Self {
+        let lengths = code_lengths(histogram, limit);
+
+        let mut counts = [0u16; MAX_CODE_LENGTH as usize + 1];
+        for &length in &lengths {
+            counts[length as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut next = [0u16; MAX_CODE_LENGTH as usize + 1];
+        let mut code = 0;
+        for length in 1..next.len() {
+            code = (code + counts[length - 1]) << 1;
+            next[length] = code;
+        }
+        let codes = lengths
+            .iter()
+            .map(|&length| {
+                if length == 0 {
+                    return 0;
+                }
+                let code = next[length as usize];
+                next[length as usize] += 1;
+                code.reverse_bits() >> (16 - length)
+            })
+            .collect();
+
+        Self {
+            single: lengths.iter().filter(|&&length| length > 0).count() <= 1,
+            lengths,
+            codes,
+        }
+    }
+
+    fn write_symbol(&self, w: &mut BitWriter, symbol: usize) {
+        if !self.single {
+            w.write(self.codes[symbol] as u32, self.lengths[symbol] as u32);
+        }
+    }
+
+    fn write_header(&self, w: &mut BitWriter) {
+        let used: Vec<usize> = (0..self.lengths.len())
+            .filter(|&symbol| self.lengths[symbol] > 0)
+            .collect();
+
+        // Codes of one or two small symbols have a shorthand.
+        if used.len() <= 2 && used.iter().all(|&symbol| symbol < 256) {
+            let first = used.first().copied().unwrap_or(0) as u32;
+            w.write(1, 1);
+            w.write(used.len().max(1) as u32 - 1, 1);
+            if first < 2 {
+                w.write(0, 1);
+                w.write(first, 1);
+            } else {
+                w.write(1, 1);
+                w.write(first, 8);
+            }
+            if let Some(&second) = used.get(1) {
+                w.write(second as u32, 8);
+            }
+            return;
+        }
+
+        // Otherwise the code lengths are run-length encoded, with their own prefix code.
+        let runs = run_lengths(&self.lengths);
+        let mut histogram = [0; 19];
+        for &(symbol, _) in &runs {
+            histogram[symbol as usize] += 1;
+        }
+        let code = PrefixCode::new(&histogram, MAX_CODE_LENGTH_CODE_LENGTH);
+        let count = CODE_LENGTH_ORDER
+            .iter()
+            .rposition(|&symbol| code.lengths[symbol] > 0)
+            .map_or(0, |i| i + 1)
+            .max(4);
+
+        w.write(0, 1);
+        w.write(count as u32 - 4, 4);
+        for &symbol in &CODE_LENGTH_ORDER[..count] {
+            w.write(code.lengths[symbol] as u32, 3);
+        }
+        w.write(0, 1); // every symbol has a length
+        for (symbol, extra) in runs {
+            code.write_symbol(w, symbol as usize);
+            match symbol {
+                16 => w.write(extra, 2),
+                17 => w.write(extra, 3),
+                18 => w.write(extra, 7),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Run-length encode code lengths into code length symbols and their extra bits: 16
+/// repeats the previous length 3-6 times, 17 and 18 repeat zero 3-10 and 11-138 times.
+fn run_lengths(lengths: &[u8]) -> Vec<(u8, u32)> {
+    let mut runs = Vec::new();
+    let mut i = 0;
+    while i < lengths.len() {
+        let length = lengths[i];
+        let run = lengths[i..].iter().take_while(|&&l| l == length).count();
+        i += run;
+
+        let mut left = run;
+        if length == 0 {
+            while left >= 11 {
+                let n = left.min(138);
+                runs.push((18, (n - 11) as u32));
+                left -= n;
+            }
+            if left >= 3 {
+                runs.push((17, (left - 3) as u32));
+                left = 0;
+            }
+        } else {
+            runs.push((length, 0));
+            left -= 1;
+            while left >= 3 {
+                let n = left.min(6);
+                runs.push((16, (n - 3) as u32));
+                left -= n;
+            }
+        }
+        runs.extend(core::iter::repeat_n((length, 0), left));
+    }
+    runs
+}
+
+/// Packs bits least significant first.
+#[derive(Default)]
+struct BitWriter {
+    data: Vec<u8>,
+    bits: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        debug_assert!(bits <= 32 && (bits == 32 || value >> bits == 0));
+        self.bits |= (value as u64) << self.count;
+        self.count += bits;
+        while self.count >= 8 {
+            self.data.push(self.bits as u8);
+            self.bits >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.data.push(self.bits as u8);
+        }
+        self.data
+    }
+}